@@ -0,0 +1,292 @@
+//! Absurdle: Wordle against an adversary.
+//!
+//! In Absurdle the answer is never fixed. After each guess the host splits the
+//! remaining words by the feedback they would give ([`WordleSolver::partition`])
+//! and keeps whichever group is worst for the player, so the game is only won
+//! by guessing the last word left. [`AbsurdleGame`] hosts a game and
+//! [`solve_absurdle`] searches for the shortest guess sequence forcing a win.
+
+use crate::cache::DecisionKey;
+use crate::constraints::HardModeConstraints;
+use crate::error::{validate_word, WordleError};
+use crate::feedback::{Feedback, FeedbackPattern};
+use crate::solver::WordleSolver;
+use rayon::prelude::*;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// How the adversary chooses which words to keep
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AdversaryRule {
+    /// Keep the most words, as Absurdle does
+    #[default]
+    Largest,
+    /// Keep the words that are hardest to split next: those whose best next
+    /// guess still leaves the most words in the worst case
+    Evasive,
+}
+
+impl AdversaryRule {
+    /// The rule called `name`, ignoring case. `absurdle` is accepted for
+    /// [`AdversaryRule::Largest`], the rule the game itself plays.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "largest" | "absurdle" => Some(AdversaryRule::Largest),
+            "evasive" => Some(AdversaryRule::Evasive),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AdversaryRule::Largest => "largest",
+            AdversaryRule::Evasive => "evasive",
+        }
+    }
+}
+
+/// The adversary's reply when guess index `guess` is played with `candidates`
+/// left under `constraints`: the feedback it gives and the words it keeps.
+///
+/// Ties between groups go to the feedback revealing the least, with the
+/// fewest greens and then the fewest yellows, so a win is only conceded when
+/// it is the only possible feedback.
+pub fn adversary_response(
+    solver: &WordleSolver,
+    guess: usize,
+    candidates: &[usize],
+    constraints: &HardModeConstraints,
+    rule: AdversaryRule,
+) -> (FeedbackPattern, Vec<usize>) {
+    let buckets = solver.partition(guess, candidates);
+    let evasion: Vec<usize> = match rule {
+        AdversaryRule::Largest => vec![0; buckets.len()],
+        AdversaryRule::Evasive => buckets
+            .iter()
+            .map(|(pattern, bucket)| {
+                let mut next = constraints.clone();
+                next.update(solver.word(guess), *pattern);
+                escape_size(solver, bucket, &next)
+            })
+            .collect(),
+    };
+
+    buckets
+        .into_iter()
+        .zip(evasion)
+        .max_by_key(|((pattern, bucket), evasion)| {
+            let (greens, yellows) = revealed(*pattern);
+            (*evasion, bucket.len(), Reverse(greens), Reverse(yellows), Reverse(pattern.code()))
+        })
+        .map(|(response, _)| response)
+        .expect("a guess always gets some feedback")
+}
+
+/// Green and yellow tiles in a pattern
+fn revealed(pattern: FeedbackPattern) -> (usize, usize) {
+    let feedbacks = pattern.to_feedbacks();
+    let count = |kind: Feedback| feedbacks.iter().filter(|&&f| f == kind).count();
+    (count(Feedback::Correct), count(Feedback::Present))
+}
+
+/// Words the player's best next guess leaves in the worst case, not
+/// counting a win
+fn escape_size(solver: &WordleSolver, candidates: &[usize], constraints: &HardModeConstraints) -> usize {
+    let win = FeedbackPattern::all_correct(solver.word_length()).index();
+    solver
+        .valid_guesses_under(constraints)
+        .into_par_iter()
+        .map(|g| {
+            let mut counts = solver.pattern_counts(g, candidates);
+            counts[win] = 0;
+            counts.into_iter().max().unwrap_or(0) as usize
+        })
+        .min()
+        .unwrap_or(candidates.len())
+}
+
+/// A game of Absurdle over the solver's answers
+#[derive(Debug, Clone)]
+pub struct AbsurdleGame {
+    solver: WordleSolver,
+    rule: AdversaryRule,
+}
+
+impl AbsurdleGame {
+    /// Start a game with every one of the solver's answers possible
+    pub fn new(solver: &WordleSolver, rule: AdversaryRule) -> Self {
+        let mut solver = solver.clone();
+        solver.reset();
+        Self { solver, rule }
+    }
+
+    /// Play `guess`, returning the adversary's feedback.
+    ///
+    /// The guess must be in the solver's guess list.
+    pub fn guess(&mut self, guess: &str) -> Result<FeedbackPattern, WordleError> {
+        validate_word(guess, self.solver.word_length())?;
+        let g = self
+            .solver
+            .guess_index_of(guess)
+            .ok_or_else(|| WordleError::NotInDictionary(guess.to_string()))?;
+        let (pattern, _) = adversary_response(
+            &self.solver,
+            g,
+            &self.solver.candidate_indices(),
+            self.solver.constraints(),
+            self.rule,
+        );
+        self.solver.apply_feedback(guess, pattern)?;
+        Ok(pattern)
+    }
+
+    /// Whether the last guess was the only word left
+    pub fn is_won(&self) -> bool {
+        self.history().last().is_some_and(|(_, pattern)| pattern.is_win())
+    }
+
+    /// Guesses and feedback so far, oldest first
+    pub fn history(&self) -> &[(String, FeedbackPattern)] {
+        self.solver.history()
+    }
+
+    /// Words the adversary could still be thinking of
+    pub fn possible_answers(&self) -> Vec<&str> {
+        self.solver.possible_answers()
+    }
+
+    pub fn remaining_count(&self) -> usize {
+        self.solver.remaining_count()
+    }
+
+    pub fn rule(&self) -> AdversaryRule {
+        self.rule
+    }
+}
+
+/// Limits for [`solve_absurdle`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbsurdleConfig {
+    pub rule: AdversaryRule,
+    /// Longest guess sequence to look for
+    pub max_guesses: usize,
+    /// Only try this many of the most promising guesses at each step
+    pub breadth: usize,
+}
+
+impl Default for AbsurdleConfig {
+    fn default() -> Self {
+        Self {
+            rule: AdversaryRule::Largest,
+            max_guesses: 8,
+            breadth: 10,
+        }
+    }
+}
+
+/// Find the shortest guess sequence winning against the adversary from the
+/// solver's current candidates, returning each guess with the feedback it
+/// gets (the last one a win).
+///
+/// Guesses follow the solver's hard mode rules, and are tried in order of the
+/// largest group of words they could leave. Only `config.breadth` guesses
+/// are tried at each step, so a shorter sequence may exist. Returns `None` if
+/// none of at most `config.max_guesses` guesses was found.
+pub fn solve_absurdle(solver: &WordleSolver, config: AbsurdleConfig) -> Option<Vec<(String, FeedbackPattern)>> {
+    let mut search = Search {
+        solver,
+        config,
+        failed: HashMap::new(),
+    };
+    let candidates = &solver.candidate_indices();
+    if candidates.is_empty() {
+        return None;
+    }
+
+    // Iterative deepening, so the first sequence found is the shortest
+    let guesses = (1..=config.max_guesses)
+        .find_map(|limit| search.solve(candidates, solver.constraints(), limit))?;
+
+    let mut constraints = solver.constraints().clone();
+    let mut candidates = candidates.to_vec();
+    let mut steps = Vec::with_capacity(guesses.len());
+    for g in guesses {
+        let (pattern, bucket) = adversary_response(solver, g, &candidates, &constraints, config.rule);
+        constraints.update(solver.word(g), pattern);
+        candidates = bucket;
+        steps.push((solver.word(g).to_string(), pattern));
+    }
+    Some(steps)
+}
+
+struct Search<'a> {
+    solver: &'a WordleSolver,
+    config: AbsurdleConfig,
+    /// Most guesses already known not to be enough for a node
+    failed: HashMap<DecisionKey, usize>,
+}
+
+impl Search<'_> {
+    /// Guess indices winning within `limit` guesses, or `None`
+    fn solve(
+        &mut self,
+        candidates: &[usize],
+        constraints: &HardModeConstraints,
+        limit: usize,
+    ) -> Option<Vec<usize>> {
+        if limit == 0 {
+            return None;
+        }
+        if let [only] = candidates {
+            return Some(vec![self.solver.guess_index_of_answer(*only)]);
+        }
+        // With several words left the adversary never concedes a win on this guess
+        if limit == 1 {
+            return None;
+        }
+
+        let key = DecisionKey::of_indices(self.solver, candidates, constraints);
+        if self.failed.get(&key).is_some_and(|&failed| failed >= limit) {
+            return None;
+        }
+
+        for guess in self.options(candidates, constraints) {
+            let (pattern, bucket) = adversary_response(self.solver, guess, candidates, constraints, self.config.rule);
+            if pattern.is_win() {
+                return Some(vec![guess]);
+            }
+            if bucket.len() == candidates.len() {
+                continue;
+            }
+            let mut next = constraints.clone();
+            next.update(self.solver.word(guess), pattern);
+            if let Some(mut rest) = self.solve(&bucket, &next, limit - 1) {
+                rest.insert(0, guess);
+                return Some(rest);
+            }
+        }
+
+        self.failed.insert(key, limit);
+        None
+    }
+
+    /// Guesses worth trying, smallest worst case first
+    fn options(&self, candidates: &[usize], constraints: &HardModeConstraints) -> Vec<usize> {
+        let is_candidate = self.solver.candidate_mask(candidates);
+        let mut scored: Vec<(usize, (usize, usize))> = self
+            .solver
+            .valid_guesses_under(constraints)
+            .into_par_iter()
+            .map(|g| (g, self.solver.bucket_stats(g, candidates)))
+            .filter(|&(_, (largest, _))| largest < candidates.len())
+            .collect();
+        scored.sort_by(|(ga, (la, ba)), (gb, (lb, bb))| {
+            la.cmp(lb)
+                .then(is_candidate[*gb].cmp(&is_candidate[*ga]))
+                .then(bb.cmp(ba))
+                .then(ga.cmp(gb))
+        });
+        scored.truncate(self.config.breadth);
+        scored.into_iter().map(|(g, _)| g).collect()
+    }
+}
@@ -0,0 +1,139 @@
+//! Solving many targets at once, with machine-readable output.
+//!
+//! [`solve_batch`] plays one game per target in parallel and keeps every
+//! guess, so two solver versions can be compared game by game by diffing
+//! [`batch_to_csv`] or [`batch_to_json`] output.
+
+use crate::error::WordleError;
+use crate::feedback::FeedbackPattern;
+use crate::solver::{SolveOutcome, SolveResult};
+use rayon::prelude::*;
+use serde::Serialize;
+
+/// How one target was played
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchResult {
+    pub target: String,
+    /// Every guess with its feedback, in order
+    pub guesses: Vec<(String, FeedbackPattern)>,
+    /// How the game ended, or `None` if it could not be played
+    pub outcome: Option<SolveOutcome>,
+    /// Why the target could not be played at all
+    pub error: Option<WordleError>,
+}
+
+impl BatchResult {
+    pub fn new(target: &str, result: Result<SolveResult, WordleError>) -> Self {
+        match result {
+            Ok(result) => Self {
+                target: target.to_string(),
+                guesses: result.guesses,
+                outcome: Some(result.outcome),
+                error: None,
+            },
+            Err(e) => Self {
+                target: target.to_string(),
+                guesses: Vec::new(),
+                outcome: None,
+                error: Some(e),
+            },
+        }
+    }
+
+    /// Whether the final guess was the target
+    pub fn is_solved(&self) -> bool {
+        self.outcome == Some(SolveOutcome::Solved)
+    }
+
+    /// The outcome's [name](SolveOutcome::name), or `error` if the target
+    /// could not be played
+    fn outcome_name(&self) -> &'static str {
+        self.outcome.map_or("error", SolveOutcome::name)
+    }
+
+    fn words(&self) -> Vec<&str> {
+        self.guesses.iter().map(|(guess, _)| guess.as_str()).collect()
+    }
+
+    fn patterns(&self) -> Vec<String> {
+        self.guesses
+            .iter()
+            .map(|(_, pattern)| pattern.to_letter_string())
+            .collect()
+    }
+}
+
+/// Play every target in parallel with `solve`, keeping the targets' order.
+///
+/// `solve` is typically `|target| solver.clone().solve_for_target(target)`.
+pub fn solve_batch<F>(targets: &[String], solve: F) -> Vec<BatchResult>
+where
+    F: Fn(&str) -> Result<SolveResult, WordleError> + Sync,
+{
+    targets
+        .par_iter()
+        .map(|target| BatchResult::new(target, solve(target)))
+        .collect()
+}
+
+/// Format as CSV with a header row. Guesses and patterns are space-separated
+/// within their columns.
+pub fn batch_to_csv(results: &[BatchResult]) -> String {
+    let mut csv = String::from("target,guesses,solved,outcome,path,patterns,error\n");
+    for result in results {
+        let error = result.error.as_ref().map(|e| e.to_string()).unwrap_or_default();
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            result.target,
+            result.guesses.len(),
+            result.is_solved(),
+            result.outcome_name(),
+            result.words().join(" "),
+            result.patterns().join(" "),
+            csv_field(&error)
+        ));
+    }
+    csv
+}
+
+/// Quote a field if it contains a comma, quote or newline
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Format as a JSON array with one object per target
+pub fn batch_to_json(results: &[BatchResult]) -> String {
+    let rows: Vec<JsonRow> = results.iter().map(JsonRow::from).collect();
+    serde_json::to_string_pretty(&rows).expect("results serialize to JSON")
+}
+
+/// JSON shape of one result
+#[derive(Debug, Serialize)]
+struct JsonRow<'a> {
+    target: &'a str,
+    guesses: usize,
+    solved: bool,
+    outcome: &'static str,
+    path: Vec<&'a str>,
+    patterns: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl<'a> From<&'a BatchResult> for JsonRow<'a> {
+    fn from(result: &'a BatchResult) -> Self {
+        JsonRow {
+            target: &result.target,
+            guesses: result.guesses.len(),
+            solved: result.is_solved(),
+            outcome: result.outcome_name(),
+            path: result.words(),
+            patterns: result.patterns(),
+            error: result.error.as_ref().map(|e| e.to_string()),
+        }
+    }
+}
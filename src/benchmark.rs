@@ -0,0 +1,328 @@
+//! Detailed benchmarks over every answer.
+//!
+//! [`WordleSolver::benchmark_average_guesses`] and
+//! [`WordleSolver::benchmark_guess_distribution`] give the headline numbers.
+//! [`benchmark`] plays the same games but keeps each one, so the report can
+//! list the failures and hardest words with their paths, the spread of guess
+//! counts and how each opening guess fared. Reports serialize to JSON.
+//!
+//! Games are played without a guess limit by default, so a word needing
+//! eight guesses is reported as eight rather than cut off at six. A game
+//! fails if it is not solved within the limit it was played with, or within
+//! six guesses when there is none.
+//!
+//! [`explore_openers`] runs a full benchmark for each of several forced
+//! opening guesses and ranks them.
+
+use crate::constraints::HardMode;
+use crate::error::WordleError;
+use crate::feedback::FeedbackPattern;
+use crate::solver::{SolveOutcome, SolveResult, WordleSolver};
+use crate::strategy::{EntropyStrategy, GuessStrategy};
+use crate::MAX_GUESSES;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Percentiles of the guess count included in every report
+const PERCENTILES: &[u8] = &[50, 75, 90, 95, 99];
+
+/// What to benchmark
+#[derive(Debug, Clone)]
+pub struct BenchmarkConfig {
+    /// Strategy to play, or `None` for the solver's own
+    pub strategy: Option<Arc<dyn GuessStrategy>>,
+    /// Hard mode rules to play under, or `None` for the solver's own
+    pub hard_mode: Option<HardMode>,
+    /// How many of the hardest words to list
+    pub hardest: usize,
+    /// Opening guesses to compare. Every answer is played once per opener;
+    /// when empty, the strategy picks its own opener.
+    pub openers: Vec<String>,
+    /// Guesses allowed per game, or `None` (the default) for no limit. Games
+    /// without a limit still fail if they take more than six guesses.
+    pub max_guesses: Option<usize>,
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        Self {
+            strategy: None,
+            hard_mode: None,
+            hardest: 10,
+            openers: Vec::new(),
+            max_guesses: None,
+        }
+    }
+}
+
+/// One game played by the benchmark
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GameRecord {
+    pub answer: String,
+    /// Every guess with its feedback, in order
+    pub guesses: Vec<(String, FeedbackPattern)>,
+    pub outcome: SolveOutcome,
+    /// Guesses the game could take without failing (see [`BenchmarkReport::guess_limit`])
+    #[serde(skip)]
+    guess_limit: usize,
+}
+
+impl GameRecord {
+    fn new(answer: &str, result: SolveResult, guess_limit: usize) -> Self {
+        Self {
+            answer: answer.to_string(),
+            guesses: result.guesses,
+            outcome: result.outcome,
+            guess_limit,
+        }
+    }
+
+    /// Whether the answer was missed or took more guesses than the limit
+    pub fn is_failure(&self) -> bool {
+        self.outcome != SolveOutcome::Solved || self.guesses.len() > self.guess_limit
+    }
+
+    fn opener(&self) -> &str {
+        self.guesses.first().map_or("", |(guess, _)| guess)
+    }
+}
+
+/// Results for the games starting with one opening guess
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OpenerStats {
+    pub opener: String,
+    pub games: usize,
+    pub average_guesses: f64,
+    pub max_guesses: usize,
+    pub failures: usize,
+}
+
+/// Everything learned from playing every answer
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BenchmarkReport {
+    pub strategy: String,
+    pub hard_mode: String,
+    pub games: usize,
+    /// Guesses a game may take before it counts as a failure: the limit it
+    /// was played with, or six when there was none
+    pub guess_limit: usize,
+    /// Games solved within `guess_limit` guesses
+    pub solved: usize,
+    /// Mean guesses per game, counting unsolved games at the guesses they used
+    pub average_guesses: f64,
+    /// Population standard deviation of the guesses per game
+    pub std_dev: f64,
+    /// Guesses needed by that percentage of games, keyed by percentage
+    pub percentiles: BTreeMap<u8, usize>,
+    /// `(guesses, games)` for every guess count that occurred
+    pub distribution: Vec<(usize, usize)>,
+    /// Every game not solved within `guess_limit` guesses
+    pub failures: Vec<GameRecord>,
+    /// The games needing the most guesses, failures first
+    pub hardest: Vec<GameRecord>,
+    /// Per-opener results, best first (see [`explore_openers`])
+    pub openers: Vec<OpenerStats>,
+    pub seconds: f64,
+}
+
+impl BenchmarkReport {
+    /// Format as pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report serializes to JSON")
+    }
+}
+
+/// Play every answer and report on the games.
+///
+/// The solver's candidates are ignored: every game starts from a fresh copy.
+/// Fails if one of `config.openers` is not a valid guess.
+pub fn benchmark(solver: &WordleSolver, config: &BenchmarkConfig) -> Result<BenchmarkReport, WordleError> {
+    let start = Instant::now();
+    let solver = configured(solver, config);
+    play_openers(&solver, &config.openers, config, start)
+}
+
+/// A fresh copy of `solver` with `config`'s strategy, hard mode and guess limit
+fn configured(solver: &WordleSolver, config: &BenchmarkConfig) -> WordleSolver {
+    let mut solver = solver.clone();
+    solver.reset();
+    if let Some(strategy) = &config.strategy {
+        solver.set_strategy(strategy.clone());
+    }
+    if let Some(mode) = config.hard_mode {
+        solver.set_hard_mode_rules(mode);
+    }
+    solver.set_max_guesses(config.max_guesses);
+    solver
+}
+
+/// Benchmark an already [`configured`] solver once per opener, or once with
+/// its own opening when `openers` is empty
+fn play_openers(
+    solver: &WordleSolver,
+    openers: &[String],
+    config: &BenchmarkConfig,
+    start: Instant,
+) -> Result<BenchmarkReport, WordleError> {
+    let guess_limit = config.max_guesses.unwrap_or(MAX_GUESSES);
+
+    // Each opener is played as a one-guess opening the solver always follows
+    let mut solvers = Vec::with_capacity(openers.len().max(1));
+    for opener in openers {
+        let mut solver = solver.clone();
+        solver.set_opening(vec![opener.clone()])?;
+        solver.set_opening_cutoff(0);
+        solvers.push(solver);
+    }
+    if solvers.is_empty() {
+        solvers.push(solver.clone());
+    }
+
+    let games: Vec<GameRecord> = solvers
+        .iter()
+        .flat_map(|solver| {
+            solver
+                .solve_every_answer()
+                .into_iter()
+                .zip(solver.answers())
+                .map(|(result, answer)| GameRecord::new(answer, result, guess_limit))
+        })
+        .collect();
+
+    Ok(summarize(solver, games, guess_limit, config.hardest, start.elapsed().as_secs_f64()))
+}
+
+/// The `k` highest-entropy opening guesses for a fresh game
+pub fn top_entropy_openers(solver: &WordleSolver, k: usize) -> Vec<String> {
+    let mut solver = solver.clone();
+    solver.reset();
+    EntropyStrategy
+        .rank(&solver, k)
+        .into_iter()
+        .map(|analysis| analysis.word)
+        .collect()
+}
+
+/// Benchmark every answer once per opener and rank the openers: fewest
+/// failures first, then fewest average guesses, then the best worst case.
+///
+/// Each opener gets a full benchmark with `config`, whose own `openers` are
+/// ignored. The solver is configured once, so every opener shares one
+/// [`DecisionCache`](crate::cache::DecisionCache) and a state reached after
+/// several openers is only ranked once. Openers are benchmarked in parallel,
+/// and `progress` is called with the number finished and the total after
+/// each one.
+pub fn explore_openers<P>(
+    solver: &WordleSolver,
+    openers: &[String],
+    config: &BenchmarkConfig,
+    progress: P,
+) -> Result<Vec<OpenerStats>, WordleError>
+where
+    P: Fn(usize, usize) + Sync,
+{
+    let solver = configured(solver, config);
+    let done = AtomicUsize::new(0);
+    let mut stats = openers
+        .par_iter()
+        .map(|opener| {
+            let report = play_openers(&solver, std::slice::from_ref(opener), config, Instant::now())?;
+            progress(done.fetch_add(1, Ordering::Relaxed) + 1, openers.len());
+            Ok(report.openers.into_iter().next().expect("one opener was played"))
+        })
+        .collect::<Result<Vec<_>, WordleError>>()?;
+    rank_openers(&mut stats);
+    Ok(stats)
+}
+
+/// Failures come first: a stuck or unsolved game only counts the guesses it
+/// used, so an opener that often fails can still have a low average
+fn rank_openers(stats: &mut [OpenerStats]) {
+    stats.sort_by(|a, b| {
+        a.failures
+            .cmp(&b.failures)
+            .then(a.average_guesses.total_cmp(&b.average_guesses))
+            .then(a.max_guesses.cmp(&b.max_guesses))
+    });
+}
+
+fn summarize(
+    solver: &WordleSolver,
+    games: Vec<GameRecord>,
+    guess_limit: usize,
+    hardest: usize,
+    seconds: f64,
+) -> BenchmarkReport {
+    let mut counts: Vec<usize> = games.iter().map(|game| game.guesses.len()).collect();
+    counts.sort_unstable();
+
+    let n = counts.len().max(1) as f64;
+    let average_guesses = counts.iter().sum::<usize>() as f64 / n;
+    let variance = counts
+        .iter()
+        .map(|&count| (count as f64 - average_guesses).powi(2))
+        .sum::<f64>()
+        / n;
+
+    // Nearest-rank percentiles
+    let percentiles = PERCENTILES
+        .iter()
+        .filter(|_| !counts.is_empty())
+        .map(|&p| {
+            let rank = (p as usize * counts.len()).div_ceil(100).max(1);
+            (p, counts[rank - 1])
+        })
+        .collect();
+
+    let mut distribution: Vec<(usize, usize)> = Vec::new();
+    for &count in &counts {
+        match distribution.last_mut() {
+            Some((guesses, games)) if *guesses == count => *games += 1,
+            _ => distribution.push((count, 1)),
+        }
+    }
+
+    let mut by_opener: BTreeMap<&str, Vec<&GameRecord>> = BTreeMap::new();
+    for game in &games {
+        by_opener.entry(game.opener()).or_default().push(game);
+    }
+    let mut openers: Vec<OpenerStats> = by_opener
+        .into_iter()
+        .map(|(opener, games)| OpenerStats {
+            opener: opener.to_string(),
+            games: games.len(),
+            average_guesses: games.iter().map(|game| game.guesses.len()).sum::<usize>() as f64 / games.len() as f64,
+            max_guesses: games.iter().map(|game| game.guesses.len()).max().unwrap_or(0),
+            failures: games.iter().filter(|game| game.is_failure()).count(),
+        })
+        .collect();
+    rank_openers(&mut openers);
+
+    let mut ranked: Vec<&GameRecord> = games.iter().collect();
+    ranked.sort_by(|a, b| {
+        b.is_failure()
+            .cmp(&a.is_failure())
+            .then(b.guesses.len().cmp(&a.guesses.len()))
+            .then(a.answer.cmp(&b.answer))
+    });
+
+    BenchmarkReport {
+        strategy: solver.strategy().name().to_string(),
+        hard_mode: solver.hard_mode_rules().name().to_string(),
+        games: games.len(),
+        guess_limit,
+        solved: games.iter().filter(|game| !game.is_failure()).count(),
+        average_guesses,
+        std_dev: variance.sqrt(),
+        percentiles,
+        distribution,
+        failures: ranked.iter().filter(|game| game.is_failure()).map(|&game| game.clone()).collect(),
+        hardest: ranked.into_iter().take(hardest).cloned().collect(),
+        openers,
+        seconds,
+    }
+}
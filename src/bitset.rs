@@ -0,0 +1,143 @@
+//! Fixed-size sets of answer indices.
+//!
+//! A [`CandidateSet`] holds one bit per answer, so membership tests are a
+//! shift and a mask, and intersecting two sets touches one word per 64
+//! answers. The solver keeps its remaining candidates in one.
+
+/// A set of indices below a fixed capacity, stored as a bitset
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CandidateSet {
+    blocks: Vec<u64>,
+    capacity: usize,
+    len: usize,
+}
+
+impl CandidateSet {
+    /// An empty set able to hold indices `0..capacity`
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            blocks: vec![0; capacity.div_ceil(64)],
+            capacity,
+            len: 0,
+        }
+    }
+
+    /// The set of every index in `0..capacity`
+    pub fn full(capacity: usize) -> Self {
+        let mut blocks = vec![u64::MAX; capacity.div_ceil(64)];
+        if capacity % 64 != 0 {
+            if let Some(last) = blocks.last_mut() {
+                *last = (1 << (capacity % 64)) - 1;
+            }
+        }
+        Self {
+            blocks,
+            capacity,
+            len: capacity,
+        }
+    }
+
+    /// The set of `indices`, each of which must be below `capacity`
+    pub fn from_indices(capacity: usize, indices: impl IntoIterator<Item = usize>) -> Self {
+        let mut set = Self::new(capacity);
+        for i in indices {
+            set.insert(i);
+        }
+        set
+    }
+
+    /// One more than the largest index the set can hold
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether `i` is in the set (false for indices beyond the capacity)
+    #[inline]
+    pub fn contains(&self, i: usize) -> bool {
+        i < self.capacity && self.blocks[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    /// Add `i`, returning whether it was newly added.
+    ///
+    /// Panics if `i` is not below the capacity.
+    pub fn insert(&mut self, i: usize) -> bool {
+        assert!(i < self.capacity, "index {} out of range for capacity {}", i, self.capacity);
+        let block = &mut self.blocks[i / 64];
+        let bit = 1 << (i % 64);
+        let added = *block & bit == 0;
+        *block |= bit;
+        self.len += added as usize;
+        added
+    }
+
+    /// Remove `i`, returning whether it was present
+    pub fn remove(&mut self, i: usize) -> bool {
+        if !self.contains(i) {
+            return false;
+        }
+        self.blocks[i / 64] &= !(1 << (i % 64));
+        self.len -= 1;
+        true
+    }
+
+    /// Keep only the indices also in `other`.
+    ///
+    /// Both sets must have the same capacity.
+    pub fn intersect_with(&mut self, other: &CandidateSet) {
+        assert_eq!(self.capacity, other.capacity, "candidate set capacity mismatch");
+        let mut len = 0;
+        for (block, other) in self.blocks.iter_mut().zip(&other.blocks) {
+            *block &= other;
+            len += block.count_ones() as usize;
+        }
+        self.len = len;
+    }
+
+    /// The indices in both sets
+    pub fn intersection(&self, other: &CandidateSet) -> CandidateSet {
+        let mut set = self.clone();
+        set.intersect_with(other);
+        set
+    }
+
+    /// Add every index in `other`.
+    ///
+    /// Both sets must have the same capacity.
+    pub fn union_with(&mut self, other: &CandidateSet) {
+        assert_eq!(self.capacity, other.capacity, "candidate set capacity mismatch");
+        let mut len = 0;
+        for (block, other) in self.blocks.iter_mut().zip(&other.blocks) {
+            *block |= other;
+            len += block.count_ones() as usize;
+        }
+        self.len = len;
+    }
+
+    /// The indices in the set, in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.blocks.iter().enumerate().flat_map(|(b, &block)| {
+            let mut bits = block;
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+                let i = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                Some(b * 64 + i)
+            })
+        })
+    }
+
+    /// The words at the set's indices of `words`, in index order
+    pub fn words<'a>(&'a self, words: &'a [String]) -> impl Iterator<Item = &'a str> + 'a {
+        self.iter().map(move |i| words[i].as_str())
+    }
+}
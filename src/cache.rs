@@ -0,0 +1,96 @@
+//! Memoized guess decisions shared between solver clones.
+//!
+//! Choosing a guess means scoring every valid guess against every candidate,
+//! and the benchmarks make the same choice over and over: every game starts
+//! from the same candidates, and games for related answers share the same
+//! early states. A [`DecisionCache`] remembers the best guess for each state
+//! so it is only computed once. Solver clones share their parent's cache.
+
+use crate::bitset::CandidateSet;
+use crate::constraints::HardModeConstraints;
+use crate::solver::{GuessAnalysis, WordleSolver};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+
+/// What a guess decision depends on, beyond the solver's settings.
+///
+/// The optimal and Absurdle searches key their memos on it too.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct DecisionKey {
+    /// Remaining answer indices
+    pub candidates: CandidateSet,
+    /// Constraints limiting the valid guesses, in hard mode only
+    pub constraints: Option<HardModeConstraints>,
+}
+
+impl DecisionKey {
+    /// The key for `candidates` under `constraints`, which are dropped unless
+    /// `solver` is in hard mode
+    pub(crate) fn new(solver: &WordleSolver, candidates: CandidateSet, constraints: &HardModeConstraints) -> Self {
+        Self {
+            candidates,
+            constraints: solver.is_hard_mode().then(|| constraints.clone()),
+        }
+    }
+
+    /// The key for a list of answer indices
+    pub(crate) fn of_indices(solver: &WordleSolver, candidates: &[usize], constraints: &HardModeConstraints) -> Self {
+        let candidates = CandidateSet::from_indices(solver.answers().len(), candidates.iter().copied());
+        Self::new(solver, candidates, constraints)
+    }
+}
+
+/// Best guesses already chosen, keyed by the remaining answers.
+///
+/// A cache is only valid for one word list, strategy, hard mode and set of
+/// priors; the solver starts a new one whenever those change.
+#[derive(Default)]
+pub struct DecisionCache {
+    decisions: RwLock<HashMap<DecisionKey, GuessAnalysis>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl DecisionCache {
+    pub(crate) fn get(&self, key: &DecisionKey) -> Option<GuessAnalysis> {
+        let decision = self.decisions.read().expect("decision cache lock").get(key).cloned();
+        let counter = if decision.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        decision
+    }
+
+    pub(crate) fn insert(&self, key: DecisionKey, decision: GuessAnalysis) {
+        self.decisions.write().expect("decision cache lock").insert(key, decision);
+    }
+
+    /// Number of states with a remembered decision
+    pub fn len(&self) -> usize {
+        self.decisions.read().expect("decision cache lock").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Lookups answered from the cache
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Lookups that had to rank the guesses
+    pub fn misses(&self) -> usize {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+impl fmt::Debug for DecisionCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecisionCache")
+            .field("decisions", &self.len())
+            .field("hits", &self.hits())
+            .field("misses", &self.misses())
+            .finish()
+    }
+}
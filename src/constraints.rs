@@ -0,0 +1,214 @@
+//! Hard mode constraint tracking.
+//!
+//! Every piece of feedback narrows down what the answer can look like. This
+//! module keeps the full picture (fixed positions, per-letter count bounds and
+//! positions each letter is known not to occupy) so guesses can be checked
+//! against either the official hard mode rules or the stricter "ultra hard"
+//! variant where every clue must be honored.
+
+use crate::error::{validate_word, WordleError};
+use crate::feedback::{Feedback, FeedbackPattern};
+
+/// Which hard mode rules guesses must follow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HardMode {
+    /// Any word may be guessed
+    #[default]
+    Off,
+    /// NYT hard mode: green letters stay in place and every revealed letter
+    /// is reused (as many times as it has been revealed)
+    Strict,
+    /// Every clue is honored: yellows move to a new position, grays are never
+    /// reused and letter counts stay within the known bounds
+    Ultra,
+}
+
+impl HardMode {
+    pub fn is_enabled(self) -> bool {
+        self != HardMode::Off
+    }
+
+    /// Parse a mode name as typed on the command line
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "off" | "normal" | "easy" => Some(HardMode::Off),
+            "on" | "hard" | "strict" | "nyt" => Some(HardMode::Strict),
+            "ultra" | "ultrahard" | "ultra-hard" => Some(HardMode::Ultra),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            HardMode::Off => "off",
+            HardMode::Strict => "strict",
+            HardMode::Ultra => "ultra",
+        }
+    }
+}
+
+/// What is known about a single letter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LetterConstraint {
+    /// Fewest copies the answer must contain
+    pub min_count: u8,
+    /// Most copies the answer may contain
+    pub max_count: u8,
+    /// Bitmask of positions the letter is known not to occupy
+    pub forbidden_positions: u16,
+}
+
+impl Default for LetterConstraint {
+    fn default() -> Self {
+        Self {
+            min_count: 0,
+            max_count: u8::MAX,
+            forbidden_positions: 0,
+        }
+    }
+}
+
+impl LetterConstraint {
+    pub fn is_forbidden_at(&self, position: usize) -> bool {
+        self.forbidden_positions & (1 << position) != 0
+    }
+}
+
+/// Hard mode constraints from previous guesses
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct HardModeConstraints {
+    /// Letters that must be in specific positions (green)
+    pub required_positions: Vec<Option<char>>,
+    /// Count bounds and excluded positions for each letter `a..=z`
+    pub letters: [LetterConstraint; 26],
+}
+
+impl HardModeConstraints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Update constraints based on a guess and its feedback, after checking
+    /// that the guess is lowercase ASCII letters with one per feedback entry
+    pub fn try_update(&mut self, guess: &str, pattern: FeedbackPattern) -> Result<(), WordleError> {
+        validate_word(guess, pattern.word_len())?;
+        self.update(guess, pattern);
+        Ok(())
+    }
+
+    /// Update constraints based on a guess and its feedback.
+    ///
+    /// The guess must be lowercase ASCII letters with one per feedback entry;
+    /// use [`HardModeConstraints::try_update`] for a guess that is not yet
+    /// validated.
+    pub fn update(&mut self, guess: &str, pattern: FeedbackPattern) {
+        let feedbacks = pattern.to_feedbacks();
+        let guess_bytes = guess.as_bytes();
+        debug_assert_eq!(guess_bytes.len(), feedbacks.len());
+        debug_assert!(guess_bytes.iter().all(u8::is_ascii_lowercase));
+        if self.required_positions.len() < feedbacks.len() {
+            self.required_positions.resize(feedbacks.len(), None);
+        }
+
+        let mut revealed = [0u8; 26];
+        let mut has_gray = [false; 26];
+
+        for (i, &fb) in feedbacks.iter().enumerate() {
+            let letter = (guess_bytes[i] - b'a') as usize;
+            match fb {
+                Feedback::Correct => {
+                    self.required_positions[i] = Some(guess_bytes[i] as char);
+                    revealed[letter] += 1;
+                }
+                Feedback::Present => {
+                    self.letters[letter].forbidden_positions |= 1 << i;
+                    revealed[letter] += 1;
+                }
+                Feedback::Absent => {
+                    self.letters[letter].forbidden_positions |= 1 << i;
+                    has_gray[letter] = true;
+                }
+            }
+        }
+
+        for (letter, constraint) in self.letters.iter_mut().enumerate() {
+            constraint.min_count = constraint.min_count.max(revealed[letter]);
+            // A gray alongside greens/yellows of the same letter means every
+            // copy has been found
+            if has_gray[letter] {
+                constraint.max_count = constraint.max_count.min(revealed[letter]);
+            }
+        }
+    }
+
+    /// Check if a word honors every clue seen so far (ultra hard mode)
+    pub fn is_valid(&self, word: &str) -> bool {
+        if !is_letters(word) || !self.greens_match(word) {
+            return false;
+        }
+
+        let counts = letter_counts(word);
+        for (letter, constraint) in self.letters.iter().enumerate() {
+            if counts[letter] < constraint.min_count || counts[letter] > constraint.max_count {
+                return false;
+            }
+        }
+
+        word.bytes()
+            .enumerate()
+            .all(|(i, b)| !self.letters[(b - b'a') as usize].is_forbidden_at(i))
+    }
+
+    /// Check if a word follows the official hard mode rules: greens in place
+    /// and every revealed letter used at least as often as it was revealed
+    pub fn is_valid_strict(&self, word: &str) -> bool {
+        if !is_letters(word) || !self.greens_match(word) {
+            return false;
+        }
+
+        let counts = letter_counts(word);
+        self.letters
+            .iter()
+            .enumerate()
+            .all(|(letter, constraint)| counts[letter] >= constraint.min_count)
+    }
+
+    /// Check a word against the rules of the given mode
+    pub fn allows(&self, word: &str, mode: HardMode) -> bool {
+        match mode {
+            HardMode::Off => true,
+            HardMode::Strict => self.is_valid_strict(word),
+            HardMode::Ultra => self.is_valid(word),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.required_positions.iter().all(|p| p.is_none())
+            && self.letters.iter().all(|c| *c == LetterConstraint::default())
+    }
+
+    fn greens_match(&self, word: &str) -> bool {
+        let word_bytes = word.as_bytes();
+        self.required_positions
+            .iter()
+            .enumerate()
+            .all(|(i, required)| match required {
+                Some(c) => word_bytes.get(i) == Some(&(*c as u8)),
+                None => true,
+            })
+    }
+}
+
+/// Whether `word` is made of lowercase ASCII letters, the only words
+/// [`letter_counts`] accepts
+fn is_letters(word: &str) -> bool {
+    word.bytes().all(|b| b.is_ascii_lowercase())
+}
+
+fn letter_counts(word: &str) -> [u8; 26] {
+    let mut counts = [0u8; 26];
+    for b in word.bytes() {
+        counts[(b - b'a') as usize] += 1;
+    }
+    counts
+}
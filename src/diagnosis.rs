@@ -0,0 +1,228 @@
+//! Explaining feedback that no word can satisfy.
+//!
+//! A single mistyped tile can rule out every answer. Given the feedback played
+//! so far, [`diagnose`] finds a minimal group of steps that contradict each
+//! other, the single-tile changes that would make the game consistent again,
+//! and any words outside the answer list that fit everything, in case the
+//! answer is simply missing from it. Those words come from the guess list, or
+//! from the embedded dictionary when every guess is also an answer.
+
+use crate::error::{validate_word, WordleError};
+use crate::feedback::{Feedback, FeedbackPattern};
+use crate::load_dictionary_with_length;
+use crate::solver::WordleSolver;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Changing one tile of one step's feedback
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Correction {
+    /// Index of the step in [`Diagnosis::steps`]
+    pub step: usize,
+    pub guess: String,
+    /// Tile position (0-based)
+    pub position: usize,
+    pub from: Feedback,
+    pub to: Feedback,
+    /// Answers left with the corrected feedback
+    pub remaining: usize,
+}
+
+impl fmt::Display for Correction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "if tile {} of {} were {} instead of {}, {} word{} remain{}",
+            self.position + 1,
+            self.guess.to_uppercase(),
+            color_name(self.to),
+            color_name(self.from),
+            self.remaining,
+            if self.remaining == 1 { "" } else { "s" },
+            if self.remaining == 1 { "s" } else { "" }
+        )
+    }
+}
+
+fn color_name(feedback: Feedback) -> &'static str {
+    match feedback {
+        Feedback::Correct => "green",
+        Feedback::Present => "yellow",
+        Feedback::Absent => "gray",
+    }
+}
+
+/// What went wrong with a sequence of feedback
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// Every step considered, oldest first
+    pub steps: Vec<(String, FeedbackPattern)>,
+    /// Indices of a minimal set of steps no answer satisfies together:
+    /// dropping any one of them leaves some answer possible. Empty if the
+    /// steps are consistent.
+    pub conflict: Vec<usize>,
+    /// Single-tile corrections leaving at least one answer, most answers
+    /// first. Empty if the steps are consistent.
+    pub corrections: Vec<Correction>,
+    /// Words outside the answer list that fit every step
+    pub unlisted_matches: Vec<String>,
+    /// How many words outside the answer list were checked; zero when every
+    /// known word is an answer
+    pub unlisted_words: usize,
+}
+
+impl Diagnosis {
+    pub fn is_consistent(&self) -> bool {
+        self.conflict.is_empty()
+    }
+
+    /// Steps where `word` as the answer would have given different
+    /// feedback, with the feedback it would have given.
+    ///
+    /// Fails if `word` is not a valid word as long as the guesses.
+    pub fn mismatches(&self, word: &str) -> Result<Vec<(usize, FeedbackPattern)>, WordleError> {
+        let mut mismatches = Vec::new();
+        for (i, (guess, pattern)) in self.steps.iter().enumerate() {
+            let expected = FeedbackPattern::try_calculate(guess, word)?;
+            if expected != *pattern {
+                mismatches.push((i, expected));
+            }
+        }
+        Ok(mismatches)
+    }
+}
+
+/// Diagnose the solver's history followed by `guess` receiving `pattern`,
+/// typically after [`WordleSolver::apply_feedback`] rejected it.
+///
+/// Every answer is considered, not only the remaining ones, and hard mode
+/// rules are ignored.
+pub fn diagnose(solver: &WordleSolver, guess: &str, pattern: FeedbackPattern) -> Result<Diagnosis, WordleError> {
+    let mut steps = solver.history().to_vec();
+    steps.push((guess.to_string(), pattern));
+    diagnose_steps(solver, steps)
+}
+
+/// Diagnose an arbitrary sequence of steps against the solver's word lists
+pub fn diagnose_steps(solver: &WordleSolver, steps: Vec<(String, FeedbackPattern)>) -> Result<Diagnosis, WordleError> {
+    for (guess, pattern) in &steps {
+        validate_word(guess, solver.word_length())?;
+        if pattern.word_len() != solver.word_length() {
+            return Err(WordleError::PatternLength {
+                pattern: pattern.to_letter_string(),
+                expected: solver.word_length(),
+            });
+        }
+    }
+
+    // Feedback each answer would have given at each step
+    let feedback = steps
+        .iter()
+        .map(|(guess, _)| match solver.guess_index_of(guess) {
+            Some(g) => Ok((0..solver.answers().len()).map(|a| solver.pattern_for(g, a)).collect()),
+            None => solver
+                .answers()
+                .iter()
+                .map(|answer| FeedbackPattern::try_calculate(guess, answer))
+                .collect(),
+        })
+        .collect::<Result<Vec<Vec<FeedbackPattern>>, WordleError>>()?;
+    let satisfied = |included: &[usize], a: usize| included.iter().all(|&i| feedback[i][a] == steps[i].1);
+    let consistent = |included: &[usize]| (0..solver.answers().len()).any(|a| satisfied(included, a));
+
+    // Drop every step the contradiction survives without
+    let mut conflict: Vec<usize> = (0..steps.len()).collect();
+    if consistent(&conflict) {
+        conflict.clear();
+    } else {
+        let mut i = 0;
+        while i < conflict.len() {
+            let without: Vec<usize> = conflict.iter().copied().filter(|&s| s != conflict[i]).collect();
+            if consistent(&without) {
+                i += 1;
+            } else {
+                conflict = without;
+            }
+        }
+    }
+
+    let mut corrections = Vec::new();
+    for (step, (guess, pattern)) in steps.iter().enumerate() {
+        if conflict.is_empty() {
+            break;
+        }
+        // Feedback at this step from each answer satisfying every other step
+        let others: Vec<usize> = (0..steps.len()).filter(|&s| s != step).collect();
+        let mut counts: HashMap<FeedbackPattern, usize> = HashMap::new();
+        for a in (0..solver.answers().len()).filter(|&a| satisfied(&others, a)) {
+            *counts.entry(feedback[step][a]).or_default() += 1;
+        }
+
+        let tiles = pattern.to_feedbacks();
+        for (position, &from) in tiles.iter().enumerate() {
+            for to in [Feedback::Correct, Feedback::Present, Feedback::Absent] {
+                if to == from {
+                    continue;
+                }
+                let mut corrected = tiles.clone();
+                corrected[position] = to;
+                if let Some(&remaining) = counts.get(&FeedbackPattern::new(corrected)) {
+                    corrections.push(Correction {
+                        step,
+                        guess: guess.clone(),
+                        position,
+                        from,
+                        to,
+                        remaining,
+                    });
+                }
+            }
+        }
+    }
+    corrections.sort_by(|a, b| b.remaining.cmp(&a.remaining).then(b.step.cmp(&a.step)));
+
+    let answers: HashSet<&str> = solver.answers().iter().map(String::as_str).collect();
+    let dictionary;
+    let words = if solver.all_words().iter().any(|word| !answers.contains(word.as_str())) {
+        solver.all_words()
+    } else {
+        dictionary = dictionary_words(solver.word_length());
+        &dictionary[..]
+    };
+    let unlisted: Vec<&String> = words
+        .iter()
+        .filter(|word| !answers.contains(word.as_str()) && validate_word(word, solver.word_length()).is_ok())
+        .collect();
+    let unlisted_words = unlisted.len();
+    let unlisted_matches = unlisted
+        .into_iter()
+        .filter(|word| {
+            steps
+                .iter()
+                .all(|(guess, p)| FeedbackPattern::try_calculate(guess, word).is_ok_and(|fb| fb == *p))
+        })
+        .cloned()
+        .collect();
+
+    Ok(Diagnosis {
+        steps,
+        conflict,
+        corrections,
+        unlisted_matches,
+        unlisted_words,
+    })
+}
+
+/// The embedded dictionary's words of `word_length` letters, loaded the first
+/// time each length is asked for
+fn dictionary_words(word_length: usize) -> Arc<[String]> {
+    static DICTIONARIES: OnceLock<Mutex<HashMap<usize, Arc<[String]>>>> = OnceLock::new();
+    DICTIONARIES
+        .get_or_init(Mutex::default)
+        .lock()
+        .expect("dictionary cache lock")
+        .entry(word_length)
+        .or_insert_with(|| load_dictionary_with_length(word_length).into())
+        .clone()
+}
@@ -0,0 +1,122 @@
+//! Errors returned by the solver's fallible operations.
+
+use crate::feedback::FeedbackPattern;
+use std::fmt;
+
+/// Why a word, a feedback pattern or a move was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordleError {
+    /// The word has a character other than a lowercase ASCII letter at
+    /// `position` (0-based)
+    InvalidWord { word: String, position: usize },
+    /// The word does not have the number of letters being played
+    WrongLength { word: String, expected: usize },
+    /// The pattern has a character other than `g`/`y`/`b` (or `2`/`1`/`0`)
+    /// at `position` (0-based)
+    InvalidPattern { pattern: String, position: usize },
+    /// The pattern does not have one entry per letter
+    PatternLength { pattern: String, expected: usize },
+    /// The word is not in the solver's word list
+    NotInDictionary(String),
+    /// The word is not one of the answers a decision tree covers
+    NotInTree(String),
+    /// None of the remaining candidates would give this feedback
+    InconsistentFeedback { guess: String, pattern: FeedbackPattern },
+    /// A pattern matrix was built from other word lists than the solver's
+    MatrixMismatch,
+    /// A multi-board game was asked for with no boards
+    NoBoards,
+    /// A multi-board guess did not get one pattern per unsolved board
+    FeedbackCount { given: usize, expected: usize },
+    /// A multi-board solve did not get one target word per board
+    TargetCount { given: usize, expected: usize },
+    /// Feedback for one board of a multi-board game was rejected
+    OnBoard { board: usize, error: Box<WordleError> },
+}
+
+impl fmt::Display for WordleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordleError::InvalidWord { word, position } => {
+                let c = word.chars().nth(*position).unwrap_or('?');
+                write!(
+                    f,
+                    "invalid word '{}': '{}' at position {} is not a lowercase letter",
+                    word,
+                    c,
+                    position + 1
+                )
+            }
+            WordleError::WrongLength { word, expected } => write!(
+                f,
+                "'{}' has {} letters, expected {}",
+                word,
+                word.chars().count(),
+                expected
+            ),
+            WordleError::InvalidPattern { pattern, position } => {
+                let c = pattern.chars().nth(*position).unwrap_or('?');
+                write!(
+                    f,
+                    "invalid feedback '{}': '{}' at position {} is not g, y or b",
+                    pattern,
+                    c,
+                    position + 1
+                )
+            }
+            WordleError::PatternLength { pattern, expected } => write!(
+                f,
+                "feedback '{}' has {} letters, expected {}",
+                pattern,
+                pattern.chars().count(),
+                expected
+            ),
+            WordleError::NotInDictionary(word) => write!(f, "'{}' is not in the word list", word),
+            WordleError::NotInTree(word) => write!(f, "'{}' is not an answer in the decision tree", word),
+            WordleError::InconsistentFeedback { guess, pattern } => write!(
+                f,
+                "no remaining word gives {} for '{}'",
+                pattern.to_letter_string(),
+                guess
+            ),
+            WordleError::MatrixMismatch => {
+                write!(f, "pattern matrix was built from different word lists")
+            }
+            WordleError::NoBoards => write!(f, "a multi-board game needs at least one board"),
+            WordleError::FeedbackCount { given, expected } => write!(
+                f,
+                "got {} feedback pattern{}, expected {} (one per unsolved board)",
+                given,
+                if *given == 1 { "" } else { "s" },
+                expected
+            ),
+            WordleError::TargetCount { given, expected } => write!(
+                f,
+                "got {} target word{}, expected {} (one per board)",
+                given,
+                if *given == 1 { "" } else { "s" },
+                expected
+            ),
+            WordleError::OnBoard { board, error } => write!(f, "board {}: {}", board + 1, error),
+        }
+    }
+}
+
+impl std::error::Error for WordleError {}
+
+/// Check that `word` is `word_length` lowercase ASCII letters
+pub fn validate_word(word: &str, word_length: usize) -> Result<(), WordleError> {
+    if let Some(position) = word.chars().position(|c| !c.is_ascii_lowercase()) {
+        return Err(WordleError::InvalidWord {
+            word: word.to_string(),
+            position,
+        });
+    }
+    if word.len() != word_length {
+        return Err(WordleError::WrongLength {
+            word: word.to_string(),
+            expected: word_length,
+        });
+    }
+    Ok(())
+}
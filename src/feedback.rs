@@ -3,7 +3,8 @@
 //! This module handles computing the feedback pattern (green/yellow/gray)
 //! for a guess against a target word.
 
-use crate::WORD_LENGTH;
+use crate::error::{validate_word, WordleError};
+use crate::{MAX_WORD_LENGTH, WORD_LENGTH};
 
 /// Represents the feedback for a single letter position.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -26,6 +27,24 @@ impl Feedback {
         }
     }
 
+    /// Convert to the letter accepted by [`Feedback::from_char`]
+    pub fn to_letter(self) -> char {
+        match self {
+            Feedback::Correct => 'g',
+            Feedback::Present => 'y',
+            Feedback::Absent => 'b',
+        }
+    }
+
+    /// Base-3 digit used in pattern codes
+    fn value(self) -> u16 {
+        match self {
+            Feedback::Absent => 0,
+            Feedback::Present => 1,
+            Feedback::Correct => 2,
+        }
+    }
+
     /// Parse from a character (g=green, y=yellow, b=black/gray)
     pub fn from_char(c: char) -> Option<Self> {
         match c.to_ascii_lowercase() {
@@ -37,34 +56,75 @@ impl Feedback {
     }
 }
 
-/// A complete feedback pattern for a 5-letter guess.
-/// Encoded as a single u8 value (0-242) for efficiency.
+/// A complete feedback pattern for a guess of up to [`MAX_WORD_LENGTH`] letters.
+///
+/// Encoded as a base-3 number in a `u16` (so 3^10 patterns fit) together with
+/// the word length it applies to.
 /// Each position can be 0 (absent), 1 (present), or 2 (correct).
-/// Pattern = p0 + 3*p1 + 9*p2 + 27*p3 + 81*p4
+/// Code = p0 + 3*p1 + 9*p2 + ... + 3^(n-1)*p(n-1)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct FeedbackPattern(pub u8);
+pub struct FeedbackPattern {
+    code: u16,
+    len: u8,
+}
 
 impl FeedbackPattern {
-    /// The pattern indicating all correct (winning)
-    pub const ALL_CORRECT: Self = Self(2 + 2 * 3 + 2 * 9 + 2 * 27 + 2 * 81); // 242
+    /// The all-correct (winning) pattern for the default word length
+    pub const ALL_CORRECT: Self = Self::all_correct(WORD_LENGTH); // 242
+
+    /// Total number of possible patterns for the default word length (3^5)
+    pub const NUM_PATTERNS: usize = Self::num_patterns(WORD_LENGTH);
+
+    /// The all-correct (winning) pattern for words of length `len`
+    pub const fn all_correct(len: usize) -> Self {
+        Self {
+            code: (Self::num_patterns(len) - 1) as u16,
+            len: len as u8,
+        }
+    }
+
+    /// Total number of possible patterns for words of length `len` (3^len)
+    pub const fn num_patterns(len: usize) -> usize {
+        3usize.pow(len as u32)
+    }
+
+    /// Create a pattern from its base-3 code
+    pub fn from_code(code: u16, len: usize) -> Self {
+        debug_assert!(len <= MAX_WORD_LENGTH);
+        debug_assert!((code as usize) < Self::num_patterns(len));
+        Self { code, len: len as u8 }
+    }
+
+    /// The base-3 code of this pattern
+    pub fn code(self) -> u16 {
+        self.code
+    }
 
-    /// Total number of possible patterns (3^5)
-    pub const NUM_PATTERNS: usize = 243;
+    /// The code as an index into a table of `num_patterns(len)` entries
+    pub fn index(self) -> usize {
+        self.code as usize
+    }
+
+    /// Length of the word this pattern describes
+    pub fn word_len(self) -> usize {
+        self.len as usize
+    }
 
     /// Create a new pattern from individual feedback values
-    pub fn new(feedbacks: [Feedback; WORD_LENGTH]) -> Self {
-        let mut pattern: u8 = 0;
-        let mut multiplier: u8 = 1;
-        for fb in feedbacks {
-            let value = match fb {
-                Feedback::Absent => 0,
-                Feedback::Present => 1,
-                Feedback::Correct => 2,
-            };
-            pattern += value * multiplier;
+    pub fn new(feedbacks: impl AsRef<[Feedback]>) -> Self {
+        let feedbacks = feedbacks.as_ref();
+        debug_assert!(feedbacks.len() <= MAX_WORD_LENGTH);
+
+        let mut code: u16 = 0;
+        let mut multiplier: u16 = 1;
+        for &fb in feedbacks {
+            code += fb.value() * multiplier;
             multiplier *= 3;
         }
-        Self(pattern)
+        Self {
+            code,
+            len: feedbacks.len() as u8,
+        }
     }
 
     /// Calculate the feedback pattern for a guess against a target word.
@@ -73,17 +133,23 @@ impl FeedbackPattern {
     /// - Green (Correct): Letter is in the correct position
     /// - Yellow (Present): Letter is in the word but wrong position
     /// - Gray (Absent): Letter is not in the word (or all instances accounted for)
+    ///
+    /// Both words must be the same length, at most [`MAX_WORD_LENGTH`] letters
+    /// and made of lowercase ASCII letters; use
+    /// [`FeedbackPattern::try_calculate`] for words that are not yet validated.
     pub fn calculate(guess: &str, target: &str) -> Self {
         let guess_bytes = guess.as_bytes();
         let target_bytes = target.as_bytes();
+        let len = guess_bytes.len();
 
-        debug_assert_eq!(guess_bytes.len(), WORD_LENGTH);
-        debug_assert_eq!(target_bytes.len(), WORD_LENGTH);
+        debug_assert_eq!(len, target_bytes.len());
+        debug_assert!(len <= MAX_WORD_LENGTH);
+        debug_assert!(guess_bytes.iter().chain(target_bytes).all(u8::is_ascii_lowercase));
 
-        let mut feedback = [Feedback::Absent; WORD_LENGTH];
+        let mut feedback = [Feedback::Absent; MAX_WORD_LENGTH];
         let mut target_remaining = [0u8; 26];
 
-        for i in 0..WORD_LENGTH {
+        for i in 0..len {
             if guess_bytes[i] == target_bytes[i] {
                 feedback[i] = Feedback::Correct;
             } else {
@@ -92,7 +158,7 @@ impl FeedbackPattern {
             }
         }
 
-        for i in 0..WORD_LENGTH {
+        for i in 0..len {
             if feedback[i] != Feedback::Correct {
                 let idx = (guess_bytes[i] - b'a') as usize;
                 if target_remaining[idx] > 0 {
@@ -102,39 +168,65 @@ impl FeedbackPattern {
             }
         }
 
-        Self::new(feedback)
+        Self::new(&feedback[..len])
+    }
+
+    /// Calculate the feedback pattern for a guess against a target word, after
+    /// checking that both are lowercase ASCII letters and the same length
+    pub fn try_calculate(guess: &str, target: &str) -> Result<Self, WordleError> {
+        validate_word(guess, guess.len().min(MAX_WORD_LENGTH))?;
+        validate_word(target, guess.len())?;
+        Ok(Self::calculate(guess, target))
     }
 
-    /// Convert pattern to array of feedbacks
-    pub fn to_feedbacks(self) -> [Feedback; WORD_LENGTH] {
-        let mut pattern = self.0;
-        let mut feedbacks = [Feedback::Absent; WORD_LENGTH];
+    /// Convert pattern to a list of feedbacks, one per letter
+    pub fn to_feedbacks(self) -> Vec<Feedback> {
+        let mut code = self.code;
+        let mut feedbacks = vec![Feedback::Absent; self.word_len()];
         for feedback in feedbacks.iter_mut() {
-            *feedback = match pattern % 3 {
+            *feedback = match code % 3 {
                 0 => Feedback::Absent,
                 1 => Feedback::Present,
                 2 => Feedback::Correct,
                 _ => unreachable!(),
             };
-            pattern /= 3;
+            code /= 3;
         }
         feedbacks
     }
 
     /// Check if this pattern represents a win (all correct)
     pub fn is_win(self) -> bool {
-        self == Self::ALL_CORRECT
+        self == Self::all_correct(self.word_len())
     }
 
-    /// Parse a pattern from a string like "gybbb" or "21000"
-    pub fn parse(s: &str) -> Option<Self> {
-        if s.len() != WORD_LENGTH {
-            return None;
+    /// Parse a 5-letter pattern from a string like "gybbb" or "21000"
+    pub fn parse(s: &str) -> Result<Self, WordleError> {
+        Self::parse_with_length(s, WORD_LENGTH)
+    }
+
+    /// Parse a pattern for words of length `len`
+    pub fn parse_with_length(s: &str, len: usize) -> Result<Self, WordleError> {
+        if len > MAX_WORD_LENGTH || s.chars().count() != len {
+            return Err(WordleError::PatternLength {
+                pattern: s.to_string(),
+                expected: len.min(MAX_WORD_LENGTH),
+            });
+        }
+        let mut feedbacks = Vec::with_capacity(len);
+        for (position, c) in s.chars().enumerate() {
+            let feedback = Feedback::from_char(c).ok_or_else(|| WordleError::InvalidPattern {
+                pattern: s.to_string(),
+                position,
+            })?;
+            feedbacks.push(feedback);
         }
-        let feedbacks: Option<Vec<_>> = s.chars().map(Feedback::from_char).collect();
-        let feedbacks = feedbacks?;
-        let arr: [Feedback; WORD_LENGTH] = feedbacks.try_into().ok()?;
-        Some(Self::new(arr))
+        Ok(Self::new(feedbacks))
+    }
+
+    /// Display as a `g`/`y`/`b` string, the format accepted by [`FeedbackPattern::parse`]
+    pub fn to_letter_string(self) -> String {
+        self.to_feedbacks().iter().map(|f| f.to_letter()).collect()
     }
 
     /// Display as emoji string
@@ -147,4 +239,11 @@ impl std::fmt::Display for FeedbackPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.to_emoji_string())
     }
-}
\ No newline at end of file
+}
+
+/// Serialized in the `g`/`y`/`b` letter form
+impl serde::Serialize for FeedbackPattern {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_letter_string())
+    }
+}
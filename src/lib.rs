@@ -2,18 +2,68 @@
 //!
 //! A multithreaded optimal Wordle solver using entropy-based information theory.
 //!
-//! The solver uses the maximum entropy strategy to find the optimal guess at each step,
-//! maximizing the expected information gain from the feedback.
+//! By default the solver uses the maximum entropy strategy to find the optimal guess at
+//! each step, maximizing the expected information gain from the feedback. Other ranking
+//! rules plug in through the [`GuessStrategy`] trait.
 
+pub mod absurdle;
+pub mod batch;
+pub mod benchmark;
+pub mod bitset;
+pub mod cache;
+pub mod constraints;
+pub mod diagnosis;
+pub mod error;
 pub mod feedback;
+pub mod lookahead;
+pub mod matrix;
+pub mod multi;
+pub mod optimal;
+pub mod packed;
+pub mod priors;
 pub mod solver;
+pub mod strategy;
+pub mod tree;
 
+pub use absurdle::{adversary_response, solve_absurdle, AbsurdleConfig, AbsurdleGame, AdversaryRule};
+pub use batch::{batch_to_csv, batch_to_json, solve_batch, BatchResult};
+pub use benchmark::{
+    benchmark, explore_openers, top_entropy_openers, BenchmarkConfig, BenchmarkReport, GameRecord,
+    OpenerStats,
+};
+pub use bitset::CandidateSet;
+pub use cache::DecisionCache;
+pub use constraints::{HardMode, HardModeConstraints, LetterConstraint};
+pub use diagnosis::{diagnose, diagnose_steps, Correction, Diagnosis};
+pub use error::{validate_word, WordleError};
 pub use feedback::{Feedback, FeedbackPattern};
-pub use solver::WordleSolver;
+pub use lookahead::{LookaheadConfig, LookaheadStrategy};
+pub use matrix::{CacheStatus, PatternMatrix};
+pub use multi::{default_max_guesses, MultiBoardSolver, MultiGuessAnalysis, MultiSolveResult};
+pub use optimal::{optimal_tree, OptimalConfig};
+pub use packed::PackedWord;
+pub use priors::WordPriors;
+pub use solver::{GuessAnalysis, SolveOutcome, SolveResult, WordleSolver};
+pub use strategy::{
+    strategy_from_name, EntropyStrategy, ExpectedRemainingStrategy, GuessStrategy,
+    LetterFrequencyStrategy, MinimaxStrategy, RandomCandidateStrategy, STRATEGY_NAMES,
+};
+pub use tree::{DecisionTree, TreeSolver};
 
-/// Word length for Wordle
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Default word length for Wordle
 pub const WORD_LENGTH: usize = 5;
 
+/// Guesses allowed in a standard game
+pub const MAX_GUESSES: usize = 6;
+
+/// Longest supported word length (3^10 feedback patterns fit in a `u16`)
+pub const MAX_WORD_LENGTH: usize = 10;
+
 /// Load the dictionary from the embedded file
 pub fn load_dictionary() -> Vec<String> {
     include_str!("../dictionary/dictionary.txt")
@@ -22,3 +72,87 @@ pub fn load_dictionary() -> Vec<String> {
         .map(|s| s.to_lowercase())
         .collect()
 }
+
+/// Load the words of length `len` from the embedded dictionary
+pub fn load_dictionary_with_length(len: usize) -> Vec<String> {
+    load_dictionary()
+        .into_iter()
+        .filter(|word| word.len() == len)
+        .collect()
+}
+
+/// Parse a word list with one word per line, validating every entry.
+///
+/// Blank lines and lines starting with `#` are skipped. Each word must be
+/// ASCII letters in a single case (uppercase words are lowercased), appear
+/// only once, and be `word_length` letters long, or as long as the first word
+/// when `word_length` is `None`. Every bad entry is reported with its line
+/// number.
+pub fn parse_word_list(text: &str, word_length: Option<usize>) -> io::Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut first_seen: HashMap<String, usize> = HashMap::new();
+    let mut expected_length = word_length;
+    let mut problems = Vec::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let line_number = i + 1;
+        let entry = line.trim();
+        if entry.is_empty() || entry.starts_with('#') {
+            continue;
+        }
+
+        if !entry.bytes().all(|b| b.is_ascii_alphabetic()) {
+            problems.push(format!("line {}: '{}' contains characters other than ASCII letters", line_number, entry));
+            continue;
+        }
+        let lowercase = entry.bytes().all(|b| b.is_ascii_lowercase());
+        let uppercase = entry.bytes().all(|b| b.is_ascii_uppercase());
+        if !lowercase && !uppercase {
+            problems.push(format!("line {}: '{}' mixes upper and lower case", line_number, entry));
+            continue;
+        }
+
+        if entry.len() > MAX_WORD_LENGTH {
+            problems.push(format!("line {}: '{}' is longer than {} letters", line_number, entry, MAX_WORD_LENGTH));
+            continue;
+        }
+        let length = *expected_length.get_or_insert(entry.len());
+        if entry.len() != length {
+            problems.push(format!(
+                "line {}: '{}' has {} letters, expected {}",
+                line_number,
+                entry,
+                entry.len(),
+                length
+            ));
+            continue;
+        }
+
+        let word = entry.to_ascii_lowercase();
+        if let Some(&first) = first_seen.get(&word) {
+            problems.push(format!("line {}: '{}' duplicates line {}", line_number, entry, first));
+            continue;
+        }
+        first_seen.insert(word.clone(), line_number);
+        words.push(word);
+    }
+
+    if problems.is_empty() {
+        return Ok(words);
+    }
+    const MAX_REPORTED: usize = 20;
+    let mut message = format!("{} invalid entries:", problems.len());
+    for problem in problems.iter().take(MAX_REPORTED) {
+        message.push_str("\n  ");
+        message.push_str(problem);
+    }
+    if problems.len() > MAX_REPORTED {
+        message.push_str(&format!("\n  ... and {} more", problems.len() - MAX_REPORTED));
+    }
+    Err(io::Error::new(io::ErrorKind::InvalidData, message))
+}
+
+/// Read and validate a word list from a file (see [`parse_word_list`])
+pub fn load_word_list(path: &Path, word_length: Option<usize>) -> io::Result<Vec<String>> {
+    parse_word_list(&fs::read_to_string(path)?, word_length)
+}
@@ -0,0 +1,201 @@
+//! Depth-limited lookahead that minimizes the expected number of guesses.
+//!
+//! One-step entropy ignores what happens after the next guess. Here a guess is
+//! scored by the expected total number of guesses needed to finish: the
+//! candidates are split into feedback buckets and the best follow-up guess is
+//! searched for in each bucket, down to a fixed depth. Beyond that depth the
+//! remaining cost is estimated from the bucket size.
+//!
+//! To stay tractable on the full dictionary only the highest-entropy guesses
+//! are expanded at each node, and a guess is abandoned as soon as a lower bound
+//! on its cost exceeds the best alternative found so far.
+//!
+//! Every candidate counts equally: buckets are weighted by their size and the
+//! bounds assume uniform answers, so [`WordPriors`](crate::priors::WordPriors)
+//! set on the solver do not change the ranking.
+
+use crate::constraints::HardModeConstraints;
+use crate::optimal::{self, lower_bound_is_exact};
+use crate::solver::{GuessAnalysis, WordleSolver};
+use crate::strategy::GuessStrategy;
+use rayon::prelude::*;
+
+/// Search limits for the lookahead strategy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LookaheadConfig {
+    /// Number of guesses searched exactly before falling back to an estimate
+    pub depth: usize,
+    /// Number of highest-entropy guesses expanded at each node
+    pub breadth: usize,
+}
+
+impl Default for LookaheadConfig {
+    fn default() -> Self {
+        Self {
+            depth: 2,
+            breadth: 10,
+        }
+    }
+}
+
+/// Rank guesses by the expected number of guesses to finish the game,
+/// treating every remaining candidate as equally likely (priors are ignored)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LookaheadStrategy {
+    pub config: LookaheadConfig,
+}
+
+impl LookaheadStrategy {
+    pub fn new(config: LookaheadConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl GuessStrategy for LookaheadStrategy {
+    fn name(&self) -> &'static str {
+        "lookahead"
+    }
+
+    fn rank(&self, solver: &WordleSolver, n: usize) -> Vec<GuessAnalysis> {
+        let config = self.config;
+        let search = Search { solver, config };
+        let candidates = &solver.candidate_indices();
+        let constraints = solver.constraints();
+        let is_candidate = solver.candidate_mask(candidates);
+
+        let shortlist = search.shortlist(candidates, constraints, config.breadth.max(n));
+        let mut scored: Vec<(usize, f64, f64)> = shortlist
+            .par_iter()
+            .map(|&(g, entropy)| {
+                let cost =
+                    search.guess_cost(g, candidates, constraints, config.depth.max(1), f64::INFINITY);
+                (g, entropy, cost)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| {
+            a.2.total_cmp(&b.2)
+                .then(is_candidate[b.0].cmp(&is_candidate[a.0]))
+                .then(b.1.total_cmp(&a.1))
+        });
+
+        scored
+            .into_iter()
+            .take(n)
+            .map(|(g, entropy, cost)| GuessAnalysis {
+                expected_guesses: Some(cost),
+                ..solver.analysis(g, entropy, is_candidate[g])
+            })
+            .collect()
+    }
+}
+
+/// Rough estimate of the guesses needed to finish `n` candidates, used once
+/// the depth limit is reached. Never below the exact lower bound.
+pub(crate) fn estimated_guesses(n: usize) -> f64 {
+    if n <= 1 {
+        return n as f64;
+    }
+    lower_bound(n).max(1.0 + (n as f64).log2() / 4.0)
+}
+
+/// No strategy can finish `n` candidates in fewer expected guesses than one
+/// that guesses a candidate and splits the rest into singletons: the
+/// [`optimal::lower_bound`] total, spread over the candidates.
+fn lower_bound(n: usize) -> f64 {
+    if n == 0 {
+        return 0.0;
+    }
+    optimal::lower_bound(n) as f64 / n as f64
+}
+
+struct Search<'a> {
+    solver: &'a WordleSolver,
+    config: LookaheadConfig,
+}
+
+impl Search<'_> {
+    /// The `size` highest-entropy guesses, plus the best guess that could
+    /// itself be the answer
+    fn shortlist(
+        &self,
+        candidates: &[usize],
+        constraints: &HardModeConstraints,
+        size: usize,
+    ) -> Vec<(usize, f64)> {
+        let ranked = self.solver.ranked_by_entropy(candidates, constraints);
+        let is_candidate = self.solver.candidate_mask(candidates);
+
+        let mut shortlist: Vec<(usize, f64)> = ranked.iter().take(size).copied().collect();
+        if !shortlist.iter().any(|&(g, _)| is_candidate[g]) {
+            if let Some(&best_candidate) = ranked.iter().find(|&&(g, _)| is_candidate[g]) {
+                shortlist.push(best_candidate);
+            }
+        }
+        shortlist
+    }
+
+    /// Expected guesses to finish `candidates` when playing optimally within
+    /// the search limits
+    fn expected_guesses(&self, candidates: &[usize], constraints: &HardModeConstraints, depth: usize) -> f64 {
+        match candidates.len() {
+            n if lower_bound_is_exact(n) => return lower_bound(n),
+            n if depth == 0 => return estimated_guesses(n),
+            _ => {}
+        }
+
+        let mut best = f64::INFINITY;
+        for (g, _) in self.shortlist(candidates, constraints, self.config.breadth) {
+            best = best.min(self.guess_cost(g, candidates, constraints, depth, best));
+        }
+        best
+    }
+
+    /// Expected guesses to finish if `guess` is played next.
+    ///
+    /// Returns early with a value of at least `bound` once the guess can no
+    /// longer beat it.
+    fn guess_cost(
+        &self,
+        guess: usize,
+        candidates: &[usize],
+        constraints: &HardModeConstraints,
+        depth: usize,
+        bound: f64,
+    ) -> f64 {
+        let n = candidates.len() as f64;
+        let mut buckets: Vec<_> = self
+            .solver
+            .partition(guess, candidates)
+            .into_iter()
+            .filter(|(pattern, _)| !pattern.is_win())
+            .collect();
+
+        // Start from the lower bound and tighten it one bucket at a time
+        let mut cost = 1.0
+            + buckets
+                .iter()
+                .map(|(_, bucket)| bucket.len() as f64 / n * lower_bound(bucket.len()))
+                .sum::<f64>();
+        if cost >= bound {
+            return cost;
+        }
+
+        // Large buckets dominate the cost, so resolve them first to prune early
+        buckets.sort_by_key(|(_, bucket)| std::cmp::Reverse(bucket.len()));
+        for (pattern, bucket) in &buckets {
+            if lower_bound_is_exact(bucket.len()) {
+                continue;
+            }
+            let mut next = constraints.clone();
+            next.update(self.solver.word(guess), *pattern);
+
+            let exact = self.expected_guesses(bucket, &next, depth - 1);
+            cost += bucket.len() as f64 / n * (exact - lower_bound(bucket.len()));
+            if cost >= bound {
+                return cost;
+            }
+        }
+        cost
+    }
+}
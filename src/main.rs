@@ -3,15 +3,25 @@
 //! Interactive command-line interface for the optimal Wordle solver.
 
 use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::thread::{self, JoinHandle};
 use std::time::Duration;
-use wordle_bot::{load_dictionary, FeedbackPattern, WordleSolver};
+use wordle_bot::{
+    batch_to_csv, batch_to_json, benchmark, diagnose, diagnose_steps, explore_openers,
+    load_dictionary_with_length, load_word_list, optimal_tree, solve_absurdle, solve_batch,
+    strategy_from_name, top_entropy_openers, validate_word, AbsurdleConfig, AbsurdleGame,
+    AdversaryRule, BenchmarkConfig, BenchmarkReport, CacheStatus, DecisionTree, Diagnosis, EntropyStrategy,
+    FeedbackPattern, GuessStrategy, HardMode, LookaheadConfig, LookaheadStrategy, MultiBoardSolver,
+    OpenerStats, OptimalConfig, SolveOutcome, SolveResult, TreeSolver, WordPriors, WordleError,
+    WordleSolver, MAX_WORD_LENGTH, STRATEGY_NAMES, WORD_LENGTH,
+};
 
 const BANNER_TEXT: &str = include_str!("text/banner.txt");
 const USAGE_TEXT: &str = include_str!("text/usage.txt");
 
+/// Progress indicator, drawn on stderr so it never mixes with output
 struct Spinner {
     running: Arc<AtomicBool>,
     handle: Option<JoinHandle<()>>,
@@ -25,13 +35,13 @@ impl Spinner {
             let frames = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
             let mut i = 0;
             while running_clone.load(Ordering::Relaxed) {
-                print!("\r{} {}", frames[i % frames.len()], message);
-                io::stdout().flush().unwrap();
+                eprint!("\r{} {}", frames[i % frames.len()], message);
+                io::stderr().flush().unwrap();
                 thread::sleep(Duration::from_millis(80));
                 i += 1;
             }
-            print!("\r{}\r", " ".repeat(message.len() + 3));
-            io::stdout().flush().unwrap();
+            eprint!("\r{}\r", " ".repeat(message.len() + 3));
+            io::stderr().flush().unwrap();
         });
         Self { running, handle: Some(handle) }
     }
@@ -50,6 +60,254 @@ impl Drop for Spinner {
     }
 }
 
+/// Options accepted before or after the subcommand
+struct Options {
+    /// Word length from `--length`; otherwise taken from the word lists
+    word_length: Option<usize>,
+    /// Answer list to load instead of the embedded dictionary
+    answers: Option<PathBuf>,
+    /// Guess list to load instead of the embedded dictionary
+    guesses: Option<PathBuf>,
+    strategy: Arc<dyn GuessStrategy>,
+    /// Saved decision tree to play instead of searching
+    tree: Option<PathBuf>,
+    /// Word frequency list weighting how likely each answer is
+    priors: Option<WordPriors>,
+    /// Guess limit from `--max-guesses`, if given (`Some(None)` for no limit)
+    max_guesses: Option<Option<usize>>,
+    /// Forced opening guesses from `--opening`
+    opening: Vec<String>,
+    /// Candidates at or below which the opening is abandoned, from `--opening-cutoff`
+    opening_cutoff: Option<usize>,
+}
+
+impl Options {
+    /// Split recognised `--flag value` options from the positional arguments
+    fn parse(args: &[String]) -> Result<(Self, Vec<String>), String> {
+        let mut options = Options {
+            word_length: None,
+            answers: None,
+            guesses: None,
+            strategy: Arc::new(EntropyStrategy),
+            tree: None,
+            priors: None,
+            max_guesses: None,
+            opening: Vec::new(),
+            opening_cutoff: None,
+        };
+        let mut positional = Vec::new();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--length" | "-l" => {
+                    let value = iter.next().ok_or("--length requires a value")?;
+                    options.word_length = match value.parse() {
+                        Ok(n) if (1..=MAX_WORD_LENGTH).contains(&n) => Some(n),
+                        _ => {
+                            return Err(format!(
+                                "Invalid word length: {} (must be 1-{})",
+                                value, MAX_WORD_LENGTH
+                            ))
+                        }
+                    };
+                }
+                "--strategy" => {
+                    let value = iter.next().ok_or("--strategy requires a value")?;
+                    options.strategy = strategy_from_name(value).ok_or_else(|| {
+                        format!(
+                            "Unknown strategy: {} (expected one of: {})",
+                            value,
+                            STRATEGY_NAMES.join(", ")
+                        )
+                    })?;
+                }
+                "--answers" => {
+                    let value = iter.next().ok_or("--answers requires a file")?;
+                    options.answers = Some(PathBuf::from(value));
+                }
+                "--guesses" => {
+                    let value = iter.next().ok_or("--guesses requires a file")?;
+                    options.guesses = Some(PathBuf::from(value));
+                }
+                "--tree" => {
+                    let value = iter.next().ok_or("--tree requires a file")?;
+                    options.tree = Some(PathBuf::from(value));
+                }
+                "--max-guesses" => {
+                    let value = iter.next().ok_or("--max-guesses requires a value")?;
+                    options.max_guesses = match value.as_str() {
+                        "0" | "none" | "unlimited" => Some(None),
+                        _ => Some(Some(value.parse().map_err(|_| {
+                            format!("Invalid guess limit: {} (use a number, or 'unlimited')", value)
+                        })?)),
+                    };
+                }
+                "--opening" => {
+                    let value = iter.next().ok_or("--opening requires a word list, e.g. crane,doily")?;
+                    options.opening = value
+                        .split(',')
+                        .map(|word| word.trim().to_lowercase())
+                        .filter(|word| !word.is_empty())
+                        .collect();
+                }
+                "--opening-cutoff" => {
+                    let value = iter.next().ok_or("--opening-cutoff requires a value")?;
+                    options.opening_cutoff = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("Invalid opening cutoff: {}", value))?,
+                    );
+                }
+                "--priors" => {
+                    let value = iter.next().ok_or("--priors requires a file")?;
+                    let priors = WordPriors::load(Path::new(value))
+                        .map_err(|e| format!("Failed to load priors from {}: {}", value, e))?;
+                    options.priors = Some(priors);
+                }
+                _ => positional.push(arg.clone()),
+            }
+        }
+
+        Ok((options, positional))
+    }
+
+    /// Load the answer and guess lists.
+    ///
+    /// Without `--guesses` the embedded dictionary is the guess list, and
+    /// without `--answers` every guess is a possible answer.
+    fn load_words(&self) -> (Vec<String>, Vec<String>) {
+        let answers = self.answers.as_ref().map(|path| read_word_list(path, self.word_length));
+        let word_length = self
+            .word_length
+            .or_else(|| answers.as_ref()?.first().map(|w| w.len()));
+
+        let guesses = match &self.guesses {
+            Some(path) => read_word_list(path, word_length),
+            None => load_dictionary_with_length(word_length.unwrap_or(WORD_LENGTH)),
+        };
+        let answers = answers.unwrap_or_else(|| guesses.clone());
+        if answers.is_empty() {
+            eprintln!(
+                "The dictionary has no {}-letter words.",
+                word_length.unwrap_or(WORD_LENGTH)
+            );
+            std::process::exit(1);
+        }
+        (answers, guesses)
+    }
+
+    /// Build a solver for the configured word lists and strategy, with its
+    /// feedback table loaded
+    fn create_solver(&self) -> WordleSolver {
+        let (answers, guesses) = self.load_words();
+        self.solver_for(answers, guesses)
+    }
+
+    /// Build a solver for word lists already returned by `load_words`
+    fn solver_for(&self, answers: Vec<String>, guesses: Vec<String>) -> WordleSolver {
+        let mut solver = match WordleSolver::with_answers(answers, guesses) {
+            Ok(solver) => solver,
+            Err(e) => {
+                eprintln!("Invalid word list: {}", e);
+                std::process::exit(1);
+            }
+        };
+        solver.set_strategy(self.strategy.clone());
+        if let Some(priors) = &self.priors {
+            solver.set_priors(priors);
+        }
+        if let Some(max_guesses) = self.max_guesses {
+            solver.set_max_guesses(max_guesses);
+        }
+        if let Err(e) = solver.set_opening(self.opening.clone()) {
+            eprintln!("Invalid --opening: {}", e);
+            std::process::exit(1);
+        }
+        if let Some(cutoff) = self.opening_cutoff {
+            solver.set_opening_cutoff(cutoff);
+        }
+        load_pattern_matrix(&mut solver);
+        solver
+    }
+
+    /// Load the `--tree` file, if one was given
+    fn load_tree(&self) -> Option<TreeSolver> {
+        let path = self.tree.as_ref()?;
+        match TreeSolver::open(path) {
+            Ok(tree) => Some(tree),
+            Err(e) => {
+                eprintln!("Failed to load tree from {}: {}", path.display(), e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// Load a word list file, exiting with the problems found if it is invalid
+fn read_word_list(path: &Path, word_length: Option<usize>) -> Vec<String> {
+    match load_word_list(path, word_length) {
+        Ok(words) => words,
+        Err(e) => {
+            eprintln!("Failed to load word list {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Location of the on-disk feedback table cache for `solver`'s word length.
+///
+/// There is one file per length: a changed dictionary or another
+/// `--answers`/`--guesses` pair fails the hash check in the cache header and
+/// the file is rebuilt in place. `WORDLE_BOT_CACHE` overrides the default
+/// under the user's cache directory; the length is added to its file name.
+fn cache_path(solver: &WordleSolver) -> PathBuf {
+    let length = solver.word_length();
+    if let Some(path) = std::env::var_os("WORDLE_BOT_CACHE") {
+        let path = PathBuf::from(path);
+        let mut name = path.file_stem().unwrap_or_default().to_os_string();
+        name.push(format!("-{}", length));
+        if let Some(extension) = path.extension() {
+            name.push(".");
+            name.push(extension);
+        }
+        return path.with_file_name(name);
+    }
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(std::env::temp_dir);
+    base.join("wordle-bot").join(format!("patterns-{}.bin", length))
+}
+
+fn load_pattern_matrix(solver: &mut WordleSolver) {
+    let spinner = Spinner::new("Loading feedback table...");
+    let path = cache_path(solver);
+    let status = solver.load_or_build_pattern_matrix(&path);
+    spinner.stop();
+    if let CacheStatus::Unsaved(e) = status {
+        eprintln!("Could not write feedback table cache {}: {}", path.display(), e);
+    }
+}
+
+/// The solver to rank guesses with: `solver` itself, or a copy using the
+/// strategy named by `name`. Prints an error and returns `None` for an
+/// unknown name.
+fn solver_for_ranking(solver: &WordleSolver, name: Option<&str>) -> Option<WordleSolver> {
+    let mut ranker = solver.clone();
+    if let Some(name) = name {
+        match strategy_from_name(name) {
+            Some(strategy) => ranker.set_strategy(strategy),
+            None => {
+                println!("Unknown strategy: {}", name);
+                println!("Available: {}", STRATEGY_NAMES.join(", "));
+                return None;
+            }
+        }
+    }
+    Some(ranker)
+}
+
 fn print_banner() {
     for line in BANNER_TEXT.lines().take(6) {
         println!("{}", line);
@@ -60,20 +318,21 @@ fn print_help() {
     println!("{}", BANNER_TEXT);
 }
 
-fn run_interactive() {
+fn run_interactive(options: &Options) {
     print_banner();
 
     println!("Loading dictionary...");
-    let words = load_dictionary();
-    println!("Loaded {} words.", words.len());
+    let mut solver = options.create_solver();
+    println!("Loaded {} words.", solver.all_words().len());
     println!();
 
-    let mut solver = WordleSolver::new(words);
     println!("Type 'help' for commands or 'suggest' to get started.");
     println!();
 
     let stdin = io::stdin();
     let mut stdout = io::stdout();
+    // The last feedback rejected as inconsistent, kept for `check`
+    let mut rejected: Option<(String, FeedbackPattern)> = None;
 
     loop {
         print!("> ");
@@ -98,13 +357,31 @@ fn run_interactive() {
                 break;
             }
             "suggest" | "s" | "best" => {
-                match solver.find_best_guess() {
+                let ranker = match solver_for_ranking(&solver, parts.get(1).copied()) {
+                    Some(ranker) => ranker,
+                    None => continue,
+                };
+                // An explicitly requested strategy overrides the opening
+                let best = if parts.len() > 1 { ranker.find_best_guess() } else { ranker.next_guess() };
+                match best {
                     Some(analysis) => {
                         println!();
                         println!("Best guess: {} ", analysis.word.to_uppercase());
                         println!("  Entropy: {:.3} bits", analysis.entropy);
                         println!("  Expected remaining: {:.1} words", analysis.expected_remaining);
-                        if analysis.is_possible_answer {
+                        println!(
+                            "  Worst case: {} words ({} feedback patterns)",
+                            analysis.worst_case_remaining, analysis.bucket_count
+                        );
+                        if let Some(expected) = analysis.expected_guesses {
+                            println!("  Expected guesses to finish: {:.3}", expected);
+                        }
+                        if analysis.is_possible_answer && solver.has_priors() {
+                            println!(
+                                "  [Y] This word is a possible answer ({:.1}% likely)",
+                                analysis.answer_probability * 100.0
+                            );
+                        } else if analysis.is_possible_answer {
                             println!("  [Y] This word is a possible answer");
                         } else {
                             println!("  [N] This word is NOT a possible answer");
@@ -112,7 +389,7 @@ fn run_interactive() {
                         println!();
                         println!("Remaining possibilities: {}", solver.remaining_count());
                         if solver.is_hard_mode() {
-                            println!("Mode: HARD");
+                            println!("Mode: HARD ({})", solver.hard_mode_rules().name());
                         }
                         println!();
                     }
@@ -122,34 +399,125 @@ fn run_interactive() {
                 }
             }
             "hard" | "hardmode" => {
-                let new_mode = !solver.is_hard_mode();
-                solver.set_hard_mode(new_mode);
-                if new_mode {
-                    println!("Hard mode: ON");
-                    println!("Guesses must use all revealed hints.");
-                } else {
-                    println!("Hard mode: OFF");
+                let new_mode = match parts.get(1) {
+                    Some(name) => match HardMode::from_name(name) {
+                        Some(mode) => mode,
+                        None => {
+                            println!("Unknown hard mode: {}", name);
+                            println!("Usage: hard [off|strict|ultra]");
+                            continue;
+                        }
+                    },
+                    None if solver.is_hard_mode() => HardMode::Off,
+                    None => HardMode::Strict,
+                };
+                solver.set_hard_mode_rules(new_mode);
+                match new_mode {
+                    HardMode::Off => println!("Hard mode: OFF"),
+                    HardMode::Strict => {
+                        println!("Hard mode: STRICT");
+                        println!("Greens must stay in place and revealed letters must be reused.");
+                    }
+                    HardMode::Ultra => {
+                        println!("Hard mode: ULTRA");
+                        println!("Guesses must honor every clue, including yellow positions and grays.");
+                    }
+                }
+            }
+            "opening" | "open" => {
+                let words: Vec<String> = parts[1..].iter().map(|word| word.to_lowercase()).collect();
+                if words.is_empty() {
+                    if solver.opening().is_empty() {
+                        println!("Opening: none (the strategy picks every guess)");
+                    } else {
+                        println!("Opening: {}", solver.opening().join(", ").to_uppercase());
+                    }
+                    println!("Usage: opening <word>... | opening off");
+                    continue;
+                }
+                let words = if words == ["off"] { Vec::new() } else { words };
+                match solver.set_opening(words) {
+                    Ok(()) if solver.opening().is_empty() => println!("Opening: none"),
+                    Ok(()) => {
+                        println!("Opening: {}", solver.opening().join(", ").to_uppercase());
+                        if solver.opening().len() > 1 {
+                            println!(
+                                "Later words are skipped once {} or fewer words remain.",
+                                solver.opening_cutoff()
+                            );
+                        }
+                    }
+                    Err(e) => println!("Error: {}", e),
                 }
             }
+            "strategy" => {
+                let name = match parts.get(1) {
+                    Some(name) => name,
+                    None => {
+                        println!("Strategy: {}", solver.strategy().name());
+                        println!("Usage: strategy <name> [depth] [breadth]");
+                        println!("Available: {}", STRATEGY_NAMES.join(", "));
+                        continue;
+                    }
+                };
+                let strategy = match strategy_from_name(name) {
+                    Some(strategy) if strategy.name() == "lookahead" => {
+                        let defaults = LookaheadConfig::default();
+                        let depth = parts.get(2).and_then(|s| s.parse().ok()).unwrap_or(defaults.depth);
+                        let breadth = parts.get(3).and_then(|s| s.parse().ok()).unwrap_or(defaults.breadth);
+                        println!("Lookahead depth {}, breadth {}", depth, breadth);
+                        Arc::new(LookaheadStrategy::new(LookaheadConfig { depth, breadth }))
+                    }
+                    Some(strategy) => strategy,
+                    None => {
+                        println!("Unknown strategy: {}", name);
+                        println!("Available: {}", STRATEGY_NAMES.join(", "));
+                        continue;
+                    }
+                };
+                println!("Strategy: {}", strategy.name());
+                solver.set_strategy(strategy);
+            }
             "top" | "t" => {
-                let n: usize = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(5);
-                let top = solver.get_top_guesses(n);
+                // Arguments are an optional count and an optional strategy, in any order
+                let n: usize = parts[1..].iter().find_map(|s| s.parse().ok()).unwrap_or(5);
+                let strategy_name = parts[1..].iter().find(|s| s.parse::<usize>().is_err()).copied();
+                let ranker = match solver_for_ranking(&solver, strategy_name) {
+                    Some(ranker) => ranker,
+                    None => continue,
+                };
+                let top = ranker.get_top_guesses(n);
 
                 if top.is_empty() {
                     println!("No possible words remaining.");
                 } else {
                     println!();
-                    println!("Top {} guesses:", top.len());
-                    println!("{:>4} {:>8} {:>8} {:>12} Possible?", "#", "Word", "Entropy", "Expected Remaining");
-                    println!("{}", "-".repeat(50));
+                    println!("Top {} guesses ({}):", top.len(), ranker.strategy().name());
+                    println!(
+                        "{:>4} {:>8} {:>8} {:>18} {:>6} {:>8} {:>9} {:>9}",
+                        "#", "Word", "Entropy", "Expected Remaining", "Worst", "Buckets", "Guesses", "Possible?"
+                    );
+                    println!("{}", "-".repeat(77));
                     for (i, analysis) in top.iter().enumerate() {
+                        let expected = analysis
+                            .expected_guesses
+                            .map(|g| format!("{:.3}", g))
+                            .unwrap_or_default();
+                        let possible = match (analysis.is_possible_answer, solver.has_priors()) {
+                            (true, true) => format!("{:.1}%", analysis.answer_probability * 100.0),
+                            (true, false) => "Y".to_string(),
+                            (false, _) => String::new(),
+                        };
                         println!(
-                            "{:>4} {:>8} {:>8.3} {:>10.3} {:>10}",
+                            "{:>4} {:>8} {:>8.3} {:>18.3} {:>6} {:>8} {:>9} {:>9}",
                             i + 1,
                             analysis.word.to_uppercase(),
                             analysis.entropy,
                             analysis.expected_remaining,
-                            if analysis.is_possible_answer { "Y" } else { "" }
+                            analysis.worst_case_remaining,
+                            analysis.bucket_count,
+                            expected,
+                            possible
                         );
                     }
                     println!();
@@ -165,56 +533,77 @@ fn run_interactive() {
                 let word = parts[1].to_lowercase();
                 let pattern_str = parts[2].to_lowercase();
 
-                match FeedbackPattern::parse(&pattern_str) {
-                    Some(pattern) => {
-                        let prev_count = solver.remaining_count();
-                        solver.apply_feedback(&word, pattern);
-                        let new_count = solver.remaining_count();
-
-                        println!();
-                        println!("Guess: {}", word.to_uppercase());
-                        println!("Feedback: {}", pattern);
+                let pattern = match FeedbackPattern::parse_with_length(&pattern_str, solver.word_length()) {
+                    Ok(pattern) => pattern,
+                    Err(e) => {
+                        println!("Error: {}", e);
                         println!(
-                            "Eliminated {} words ({} -> {})",
-                            prev_count - new_count,
-                            prev_count,
-                            new_count
+                            "Use g=green, y=yellow, b=black ({} characters)",
+                            solver.word_length()
                         );
+                        continue;
+                    }
+                };
 
-                        if pattern.is_win() {
-                            println!();
-                            println!("Congratulations! You solved it!");
-                        } else if new_count == 0 {
-                            println!();
-                            println!("Warning: No words match this feedback pattern!");
-                            println!("This might indicate an error. Use 'reset' to start over.");
-                        } else {
-                            if new_count <= 10 {
-                                println!();
-                                println!("Remaining words: {:?}", 
-                                    solver.possible_answers().iter()
-                                        .map(|s| s.to_uppercase())
-                                        .collect::<Vec<_>>());
-                            }
-                            println!();
-                            if let Some(analysis) = solver.find_best_guess() {
-                                println!("-> Best guess: {} (entropy: {:.2} bits)", 
-                                    analysis.word.to_uppercase(), analysis.entropy);
-                            }
+                let prev_count = solver.remaining_count();
+                if let Err(e) = solver.apply_feedback(&word, pattern) {
+                    println!("Error: {}", e);
+                    if let WordleError::InconsistentFeedback { .. } = e {
+                        println!("Nothing was changed.");
+                        if let Ok(diagnosis) = diagnose(&solver, &word, pattern) {
+                            print_diagnosis(&diagnosis);
                         }
+                        println!("Use 'check <word>' to see why a word was ruled out, or 'undo' to take back an earlier step.");
+                        rejected = Some((word, pattern));
+                    }
+                    continue;
+                }
+                rejected = None;
+                let new_count = solver.remaining_count();
+
+                println!();
+                println!("Guess: {}", word.to_uppercase());
+                println!("Feedback: {}", pattern);
+                println!(
+                    "Eliminated {} words ({} -> {})",
+                    prev_count - new_count,
+                    prev_count,
+                    new_count
+                );
+
+                if pattern.is_win() {
+                    println!();
+                    println!("Congratulations! You solved it!");
+                } else {
+                    if new_count <= 10 {
                         println!();
+                        println!("Remaining words: {:?}", 
+                            solver.possible_answers().iter()
+                                .map(|s| s.to_uppercase())
+                                .collect::<Vec<_>>());
                     }
-                    None => {
-                        println!("Invalid pattern: {}", pattern_str);
-                        println!("Use g=green, y=yellow, b=black (5 characters)");
+                    println!();
+                    if let Some(analysis) = solver.next_guess() {
+                        println!("-> Best guess: {} (entropy: {:.2} bits)", 
+                            analysis.word.to_uppercase(), analysis.entropy);
                     }
                 }
+                println!();
             }
             "remaining" | "r" | "left" => {
                 let remaining = solver.possible_answers();
                 println!();
                 println!("Remaining possibilities: {}", remaining.len());
-                if remaining.len() <= 20 {
+                if solver.has_priors() {
+                    // Most likely first, with each word's posterior probability
+                    let posteriors = solver.posteriors();
+                    for (word, probability) in posteriors.iter().take(20) {
+                        println!("{:>8} {:>6.2}%", word.to_uppercase(), probability * 100.0);
+                    }
+                    if posteriors.len() > 20 {
+                        println!("     ... and {} less likely words", posteriors.len() - 20);
+                    }
+                } else if remaining.len() <= 20 {
                     for (i, word) in remaining.iter().enumerate() {
                         if i > 0 && i % 10 == 0 {
                             println!();
@@ -227,24 +616,29 @@ fn run_interactive() {
             }
             "solve" => {
                 if parts.len() < 2 {
-                    println!("Usage: solve <target_word>");
+                    println!("Usage: solve <target_word> [strategy]");
                     continue;
                 }
 
                 let target = parts[1].to_lowercase();
-                if target.len() != 5 {
-                    println!("Word must be 5 letters.");
-                    continue;
-                }
+                let mut runner = match solver_for_ranking(&solver, parts.get(2).copied()) {
+                    Some(runner) => runner,
+                    None => continue,
+                };
+                runner.reset();
+                let result = match runner.solve_for_target(&target) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        println!("Error: {}", e);
+                        continue;
+                    }
+                };
 
                 println!();
                 println!("Solving for: {}", target.to_uppercase());
                 println!();
 
-                solver.reset();
-                let guesses = solver.solve_for_target(&target);
-
-                for (i, (guess, pattern)) in guesses.iter().enumerate() {
+                for (i, (guess, pattern)) in result.guesses.iter().enumerate() {
                     println!(
                         "Guess {}: {} -> {}",
                         i + 1,
@@ -254,58 +648,195 @@ fn run_interactive() {
                 }
 
                 println!();
-                if let Some((_, pattern)) = guesses.last() {
-                    if pattern.is_win() {
-                        println!("Solved in {} guesses!", guesses.len());
-                    } else {
-                        println!("Failed to solve within 6 guesses.");
+                println!("{}", outcome_message(&result));
+                println!();
+                solver.reset();
+            }
+            "optimal" | "opt" => {
+                let max_depth = match parts.get(1).map(|d| d.parse::<usize>()) {
+                    None => Some(6),
+                    Some(Ok(0)) => None,
+                    Some(Ok(depth)) => Some(depth),
+                    Some(Err(_)) => {
+                        println!("Usage: optimal [max_depth] (0 for no limit)");
+                        continue;
+                    }
+                };
+                let config = OptimalConfig { max_depth, breadth: None };
+
+                let spinner = Spinner::new("Searching for the optimal tree...");
+                let start = std::time::Instant::now();
+                let optimal = optimal_tree(&solver, config);
+                let heuristic = DecisionTree::from_strategy(&solver);
+                let elapsed = start.elapsed();
+                spinner.stop();
+
+                println!();
+                match optimal {
+                    Some(tree) => {
+                        println!("Optimal guess: {}", tree.guess.to_uppercase());
+                        println!(
+                            "  optimal:    {:.4} guesses on average ({} total, worst case {})",
+                            tree.average_guesses(),
+                            tree.total_guesses(),
+                            tree.max_depth()
+                        );
                     }
+                    None => println!("No strategy finds every remaining word within the depth limit."),
+                }
+                if let Some(tree) = heuristic {
+                    println!(
+                        "  {:<11} {:.4} guesses on average ({} total, worst case {}), opening {}",
+                        format!("{}:", solver.strategy().name()),
+                        tree.average_guesses(),
+                        tree.total_guesses(),
+                        tree.max_depth(),
+                        tree.guess.to_uppercase()
+                    );
                 }
+                println!("Time elapsed: {:.2?}", elapsed);
                 println!();
-                solver.reset();
             }
             "benchmark" | "bench" => {
-                let fresh_solver = WordleSolver::new(solver.all_words().to_vec());
                 println!();
-                println!("Running benchmark on all {} words...", fresh_solver.all_words().len());
+                println!("Running benchmark on all {} answers...", solver.answers().len());
 
+                let config = BenchmarkConfig {
+                    hardest: 5,
+                    ..BenchmarkConfig::default()
+                };
                 let spinner = Spinner::new("Computing...");
-                let start = std::time::Instant::now();
-                let distribution = fresh_solver.benchmark_guess_distribution();
-                let elapsed = start.elapsed();
+                let report = benchmark(&solver, &config).expect("no openers to validate");
                 spinner.stop();
 
-                let total: usize = distribution.iter().map(|(_, c)| c).sum();
-                let total_guesses: usize = distribution.iter().map(|(g, c)| g * c).sum();
-                let average = total_guesses as f64 / total as f64;
-
                 println!("Results:");
-                println!("{}", "=".repeat(40));
+                print_report(&report, config.hardest);
+            }
+            "openers" => {
+                let openers = match openers_to_explore(&solver, &parts[1..]) {
+                    Ok(openers) => openers,
+                    Err(e) => {
+                        println!("Error: {}", e);
+                        println!("Usage: openers [k | word...]");
+                        continue;
+                    }
+                };
+
                 println!();
-                println!("Guess distribution:");
-                for (guesses, count) in &distribution {
-                    let pct = *count as f64 / total as f64 * 100.0;
-                    let bar = "#".repeat((*count * 40 / total).max(1));
-                    println!("  {} guesses: {:>5} ({:>5.1}%) {}", guesses, count, pct, bar);
+                println!(
+                    "Benchmarking {} openers on all {} answers...",
+                    openers.len(),
+                    solver.answers().len()
+                );
+                match explore_with_progress(&solver, &openers) {
+                    Ok(stats) => print_openers(&solver, &stats),
+                    Err(e) => println!("Error: {}", e),
                 }
                 println!();
-                println!("Average guesses: {:.3}", average);
-                println!("Total words: {}", total);
-                println!("Time elapsed: {:.2?}", elapsed);
+            }
+            "check" => {
+                if parts.len() < 2 {
+                    println!("Usage: check <word>");
+                    continue;
+                }
+                let word = parts[1].to_lowercase();
+                if let Err(e) = validate_word(&word, solver.word_length()) {
+                    println!("Error: {}", e);
+                    continue;
+                }
+
+                let mut steps = solver.history().to_vec();
+                steps.extend(rejected.clone());
+                let diagnosis = match diagnose_steps(&solver, steps) {
+                    Ok(diagnosis) => diagnosis,
+                    Err(e) => {
+                        println!("Error: {}", e);
+                        continue;
+                    }
+                };
 
-                let failures = distribution.iter()
-                    .filter(|(g, _)| *g > 6)
-                    .map(|(_, c)| c)
-                    .sum::<usize>();
-                if failures > 0 {
-                    println!("Words not solved in 6 guesses: {}", failures);
+                println!();
+                if solver.answer_index_of(&word).is_some() {
+                    println!("{} is in the answer list.", word.to_uppercase());
+                } else if solver.guess_index_of(&word).is_some() {
+                    println!("{} is a valid guess but not in the answer list.", word.to_uppercase());
                 } else {
-                    println!("All words solved within 6 guesses!");
+                    println!("{} is not in the word list.", word.to_uppercase());
+                }
+                let mismatches = match diagnosis.mismatches(&word) {
+                    Ok(mismatches) => mismatches,
+                    Err(e) => {
+                        println!("Error: {}", e);
+                        continue;
+                    }
+                };
+                if mismatches.is_empty() {
+                    println!("It fits all {} feedback entries.", diagnosis.steps.len());
+                }
+                for (step, expected) in mismatches {
+                    let (guess, pattern) = &diagnosis.steps[step];
+                    println!(
+                        "  {}. {} got {}, but {} would give {}",
+                        step + 1,
+                        guess.to_uppercase(),
+                        pattern,
+                        word.to_uppercase(),
+                        expected
+                    );
+                }
+                println!();
+            }
+            "undo" | "u" | "redo" => {
+                let is_undo = !parts[0].eq_ignore_ascii_case("redo");
+                let command = if is_undo { "undo" } else { "redo" };
+                let steps = match parts.get(1).map(|s| s.parse::<usize>()) {
+                    None => 1,
+                    Some(Ok(steps)) if steps > 0 => steps,
+                    _ => {
+                        println!("Usage: {} [steps]", command);
+                        continue;
+                    }
+                };
+
+                let mut changed = Vec::new();
+                for _ in 0..steps {
+                    let step = if is_undo { solver.undo() } else { solver.redo() };
+                    match step {
+                        Some(step) => changed.push(step),
+                        None => break,
+                    }
+                }
+                if changed.is_empty() {
+                    println!("Nothing to {}.", command);
+                    continue;
+                }
+
+                println!();
+                for (guess, pattern) in &changed {
+                    println!(
+                        "{} {} {}",
+                        if is_undo { "Undid" } else { "Redid" },
+                        guess.to_uppercase(),
+                        pattern
+                    );
+                }
+                println!("Remaining possibilities: {}", solver.remaining_count());
+                println!();
+            }
+            "history" | "hist" => {
+                let history = solver.history();
+                println!();
+                if history.is_empty() {
+                    println!("No feedback applied yet.");
+                }
+                for (i, (guess, pattern)) in history.iter().enumerate() {
+                    println!("{:>3}. {} {}", i + 1, guess.to_uppercase(), pattern);
                 }
                 println!();
             }
             "reset" => {
                 solver.reset();
+                rejected = None;
                 println!("Reset to initial state. {} words available.", solver.remaining_count());
             }
             _ => {
@@ -316,8 +847,325 @@ fn run_interactive() {
     }
 }
 
+/// The openers to explore: the words in `args`, or the top `k` by entropy
+/// when `args` is empty or a single number (default 10)
+fn openers_to_explore(solver: &WordleSolver, args: &[&str]) -> Result<Vec<String>, String> {
+    match args {
+        [] => Ok(top_entropy_openers(solver, 10)),
+        [k] if k.parse::<usize>().is_ok() => Ok(top_entropy_openers(solver, k.parse().unwrap())),
+        words => words
+            .iter()
+            .map(|word| {
+                let word = word.to_lowercase();
+                match solver.guess_index_of(&word) {
+                    Some(_) => Ok(word),
+                    None => Err(WordleError::NotInDictionary(word).to_string()),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Benchmark each opener, showing progress on stderr
+fn explore_with_progress(solver: &WordleSolver, openers: &[String]) -> Result<Vec<OpenerStats>, WordleError> {
+    let stats = explore_openers(solver, openers, &BenchmarkConfig::default(), |done, total| {
+        eprint!("\rBenchmarking openers: {}/{}", done, total);
+        io::stderr().flush().unwrap();
+    });
+    eprint!("\r{}\r", " ".repeat(40));
+    stats
+}
+
+/// Print openers ranked by [`explore_openers`]
+fn print_openers(solver: &WordleSolver, stats: &[OpenerStats]) {
+    println!(
+        "{:<4} {:<10} {:>8} {:>6} {:>9} {:>8}",
+        "Rank", "Opener", "Average", "Worst", "Failures", "Entropy"
+    );
+    println!("{}", "-".repeat(50));
+    // Entropy at the start of a game, over every answer
+    let answers: Vec<usize> = (0..solver.answers().len()).collect();
+    for (i, opener) in stats.iter().enumerate() {
+        let entropy = solver
+            .guess_index_of(&opener.opener)
+            .map_or(0.0, |g| solver.entropy_among(g, &answers));
+        println!(
+            "{:<4} {:<10} {:>8.3} {:>6} {:>9} {:>8.3}",
+            i + 1,
+            opener.opener.to_uppercase(),
+            opener.average_guesses,
+            opener.max_guesses,
+            opener.failures,
+            entropy
+        );
+    }
+}
+
+/// Describe how an automatic solve ended
+fn outcome_message(result: &SolveResult) -> String {
+    let n = result.num_guesses();
+    match result.outcome {
+        SolveOutcome::Solved => format!("Solved in {} guesses.", n),
+        SolveOutcome::Unsolved => format!("Not solved within {} guesses.", n),
+        SolveOutcome::Stuck => format!(
+            "Stuck after {} guesses: the strategy cannot narrow down the remaining words.",
+            n
+        ),
+    }
+}
+
+/// Summarize a benchmark, listing up to `hardest` of the hardest words
+fn print_report(report: &BenchmarkReport, hardest: usize) {
+    println!("Strategy: {} (hard mode: {})", report.strategy, report.hard_mode);
+    println!("{}", "=".repeat(40));
+    println!();
+    println!("Guess distribution:");
+    for (guesses, count) in &report.distribution {
+        let pct = *count as f64 / report.games as f64 * 100.0;
+        let bar = "#".repeat((*count * 40 / report.games).max(1));
+        println!("  {} guesses: {:>5} ({:>5.1}%) {}", guesses, count, pct, bar);
+    }
+    println!();
+    println!("Average guesses: {:.3} (std dev {:.3})", report.average_guesses, report.std_dev);
+    let percentiles: Vec<String> = report
+        .percentiles
+        .iter()
+        .map(|(p, guesses)| format!("p{} {}", p, guesses))
+        .collect();
+    println!("Percentiles: {}", percentiles.join(", "));
+    println!("Games: {}", report.games);
+    println!("Time elapsed: {:.2}s", report.seconds);
+
+    if report.openers.len() > 1 {
+        println!();
+        println!("{:<10} {:>8} {:>6} {:>9}", "Opener", "Average", "Worst", "Failures");
+        for stats in &report.openers {
+            println!(
+                "{:<10} {:>8.3} {:>6} {:>9}",
+                stats.opener.to_uppercase(),
+                stats.average_guesses,
+                stats.max_guesses,
+                stats.failures
+            );
+        }
+    }
+
+    println!();
+    if report.failures.is_empty() {
+        println!("All words solved within {} guesses!", report.guess_limit);
+    } else {
+        println!("Words not solved in {} guesses: {}", report.guess_limit, report.failures.len());
+    }
+    if hardest > 0 && !report.hardest.is_empty() {
+        println!("Hardest words:");
+        for game in report.hardest.iter().take(hardest) {
+            let path: Vec<String> = game.guesses.iter().map(|(guess, _)| guess.to_uppercase()).collect();
+            println!(
+                "  {:<8} {}{}",
+                game.answer.to_uppercase(),
+                path.join(" "),
+                match game.outcome {
+                    SolveOutcome::Solved => "",
+                    SolveOutcome::Unsolved => " (gave up)",
+                    SolveOutcome::Stuck => " (stuck)",
+                }
+            );
+        }
+    }
+    println!();
+}
+
+/// Explain a contradiction found by [`diagnose`]
+fn print_diagnosis(diagnosis: &Diagnosis) {
+    println!();
+    println!("These entries contradict each other:");
+    for &step in &diagnosis.conflict {
+        let (guess, pattern) = &diagnosis.steps[step];
+        println!("  {}. {} {}", step + 1, guess.to_uppercase(), pattern);
+    }
+    if !diagnosis.corrections.is_empty() {
+        println!("Possible typos:");
+        for correction in diagnosis.corrections.iter().take(5) {
+            println!("  {}", correction);
+        }
+    }
+    if !diagnosis.unlisted_matches.is_empty() {
+        let words: Vec<String> = diagnosis.unlisted_matches.iter().take(10).map(|w| w.to_uppercase()).collect();
+        println!("Words outside the answer list that fit: {}", words.join(", "));
+    } else if diagnosis.unlisted_words == 0 {
+        println!("Every known word is an answer, so no word outside the answer list could be checked.");
+    }
+    println!();
+}
+
+/// Print each board's state: when it was solved, or the words left
+fn print_boards(game: &MultiBoardSolver) {
+    for b in 0..game.num_boards() {
+        let board = game.board(b);
+        match game.solved_at()[b] {
+            Some(guess) => println!("Board {}: solved on guess {}", b + 1, guess),
+            None if board.remaining_count() <= 10 => {
+                let words: Vec<String> = board.possible_answers().iter().map(|w| w.to_uppercase()).collect();
+                println!("Board {}: {} left: {}", b + 1, board.remaining_count(), words.join(", "));
+            }
+            None => println!("Board {}: {} left", b + 1, board.remaining_count()),
+        }
+    }
+}
+
+/// Interactive session for a multi-board game
+fn run_multi_board(mut game: MultiBoardSolver) {
+    let budget = game.max_guesses().map_or("unlimited".to_string(), |max| max.to_string());
+    println!("{} boards, {} guesses. Commands:", game.num_boards(), budget);
+    println!("  suggest            - Best guess for the unsolved boards");
+    println!("  top [n]            - Show the top n guesses (default: 5)");
+    println!("  guess <word> <pattern>...");
+    println!("                     - Apply feedback, one pattern per unsolved board");
+    println!("                       in board order (e.g. 'guess crane gybbb bbbbb')");
+    println!("  status             - Show every board");
+    println!("  reset              - Start over");
+    println!("  quit               - Exit");
+    println!();
+
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    loop {
+        print!("> ");
+        stdout.flush().unwrap();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap() == 0 {
+            break;
+        }
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.is_empty() {
+            continue;
+        }
+
+        match parts[0].to_lowercase().as_str() {
+            "quit" | "exit" | "q" => break,
+            "suggest" | "s" | "best" => match game.find_best_guess() {
+                Some(analysis) => {
+                    println!("Best guess: {}", analysis.word.to_uppercase());
+                    println!("  Entropy: {:.3} bits across boards", analysis.entropy);
+                    println!("  Expected boards solved: {:.2}", analysis.expected_solved);
+                    println!("  Estimated guesses left after it: {:.2}", analysis.expected_guesses);
+                }
+                None => println!("No guesses available."),
+            },
+            "top" | "t" => {
+                let n = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(5);
+                println!(
+                    "{:<4} {:<10} {:>8} {:>8} {:>10}",
+                    "#", "Word", "Entropy", "Solves", "Est. left"
+                );
+                for (i, analysis) in game.find_best_guesses(n).iter().enumerate() {
+                    println!(
+                        "{:<4} {:<10} {:>8.3} {:>8.2} {:>10.2}",
+                        i + 1,
+                        analysis.word.to_uppercase(),
+                        analysis.entropy,
+                        analysis.expected_solved,
+                        analysis.expected_guesses
+                    );
+                }
+            }
+            "guess" | "g" | "feedback" | "f" => {
+                if parts.len() < 3 {
+                    println!("Usage: guess <word> <pattern>... (one pattern per unsolved board)");
+                    continue;
+                }
+                let word = parts[1].to_lowercase();
+                let length = game.board(0).word_length();
+                let patterns: Result<Vec<FeedbackPattern>, WordleError> = parts[2..]
+                    .iter()
+                    .map(|p| FeedbackPattern::parse_with_length(p, length))
+                    .collect();
+                let applied = patterns.and_then(|patterns| game.apply_feedback(&word, &patterns));
+                if let Err(e) = applied {
+                    println!("Error: {}", e);
+                    continue;
+                }
+
+                print_boards(&game);
+                if game.all_solved() {
+                    println!();
+                    println!("All boards solved in {} guesses!", game.guesses().len());
+                } else if let Some(left) = game.guesses_left() {
+                    if left == 0 {
+                        println!();
+                        println!("Out of guesses.");
+                    } else {
+                        println!("{} guesses left", left);
+                    }
+                }
+            }
+            "status" | "remaining" | "r" => print_boards(&game),
+            "reset" => {
+                game.reset();
+                println!("Reset all boards.");
+            }
+            _ => println!("Unknown command. Try suggest, top, guess, status, reset or quit."),
+        }
+    }
+}
+
+/// Host a game of Absurdle on stdin and stdout
+fn play_absurdle(solver: &WordleSolver, rule: AdversaryRule) {
+    let mut game = AbsurdleGame::new(solver, rule);
+    println!(
+        "Absurdle ({} adversary): the answer changes to dodge your guesses.",
+        rule.name()
+    );
+    println!("Enter a guess, 'remaining' to see the words left, or 'quit'.");
+    println!();
+
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    loop {
+        print!("> ");
+        stdout.flush().unwrap();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap() == 0 {
+            break;
+        }
+        let input = line.trim().to_lowercase();
+        match input.as_str() {
+            "" => continue,
+            "quit" | "exit" | "q" => break,
+            "remaining" | "r" => {
+                let words: Vec<String> = game.possible_answers().iter().take(20).map(|w| w.to_uppercase()).collect();
+                println!("{} words left: {}", game.remaining_count(), words.join(", "));
+                continue;
+            }
+            _ => {}
+        }
+
+        match game.guess(&input) {
+            Ok(pattern) => {
+                println!("{} {}", input.to_uppercase(), pattern);
+                if game.is_won() {
+                    println!();
+                    println!("You beat Absurdle in {} guesses!", game.history().len());
+                    break;
+                }
+                println!("{} words left", game.remaining_count());
+            }
+            Err(e) => println!("Error: {}", e),
+        }
+    }
+}
+
 fn main() {
     let args: Vec<String> = std::env::args().collect();
+    let (options, args) = match Options::parse(&args) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
 
     if args.len() > 1 {
         match args[1].as_str() {
@@ -331,57 +1179,349 @@ fn main() {
                 }
 
                 let target = args[2].to_lowercase();
-                if target.len() != 5 {
-                    eprintln!("Word must be 5 letters.");
-                    std::process::exit(1);
-                }
-
-                let words = load_dictionary();
-                let mut solver = WordleSolver::new(words);
+                let result = match options.load_tree() {
+                    Some(mut tree) => tree.solve_for_target(&target),
+                    None => options.create_solver().solve_for_target(&target),
+                };
+                let result = match result {
+                    Ok(result) => result,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                };
 
                 println!("Solving for: {}", target.to_uppercase());
                 println!();
 
-                let guesses = solver.solve_for_target(&target);
-
-                for (i, (guess, pattern)) in guesses.iter().enumerate() {
+                for (i, (guess, pattern)) in result.guesses.iter().enumerate() {
                     println!("Guess {}: {} -> {}", i + 1, guess.to_uppercase(), pattern);
                 }
 
                 println!();
-                if let Some((_, pattern)) = guesses.last() {
-                    if pattern.is_win() {
-                        println!("Solved in {} guesses.", guesses.len());
-                    }
-                }
+                println!("{}", outcome_message(&result));
             }
             "benchmark" | "bench" => {
-                let words = load_dictionary();
-                let solver = WordleSolver::new(words);
+                if let Some(tree) = options.load_tree() {
+                    let tree = tree.tree();
+                    println!("Tree: {}", options.tree.as_ref().unwrap().display());
+                    println!("Answers: {}", tree.num_answers());
+                    println!("Average guesses: {:.3}", tree.average_guesses());
+                    println!("Worst case: {} guesses", tree.max_depth());
+                    return;
+                }
 
-                let spinner = Spinner::new("Running benchmark...");
-                let start = std::time::Instant::now();
-                let avg = solver.benchmark_average_guesses();
-                let elapsed = start.elapsed();
-                spinner.stop();
+                let solver = options.create_solver();
+                let usage = "Usage: wordle-bot benchmark [strategy...] [--hard <mode>] [--hardest <n>] [--opener <word>...] [--json]";
 
-                println!("Average guesses: {:.3}", avg);
-                println!("Time: {:.2?}", elapsed);
+                // Extra arguments name strategies to compare
+                let mut strategies = Vec::new();
+                let mut config = BenchmarkConfig {
+                    max_guesses: options.max_guesses.flatten(),
+                    ..BenchmarkConfig::default()
+                };
+                let mut json = false;
+                let mut rest = args[2..].iter();
+                while let Some(arg) = rest.next() {
+                    match arg.as_str() {
+                        "--json" => json = true,
+                        "--hard" => match rest.next().and_then(|name| HardMode::from_name(name)) {
+                            Some(mode) => config.hard_mode = Some(mode),
+                            None => {
+                                eprintln!("{}", usage);
+                                eprintln!("Hard modes: off, strict, ultra");
+                                std::process::exit(1);
+                            }
+                        },
+                        "--hardest" => match rest.next().and_then(|n| n.parse().ok()) {
+                            Some(n) => config.hardest = n,
+                            None => {
+                                eprintln!("{}", usage);
+                                std::process::exit(1);
+                            }
+                        },
+                        "--opener" => match rest.next() {
+                            Some(word) => config.openers.push(word.to_lowercase()),
+                            None => {
+                                eprintln!("{}", usage);
+                                std::process::exit(1);
+                            }
+                        },
+                        name => match strategy_from_name(name) {
+                            Some(strategy) => strategies.push(strategy),
+                            None => {
+                                eprintln!("Unknown strategy: {}", name);
+                                eprintln!("Available: {}", STRATEGY_NAMES.join(", "));
+                                std::process::exit(1);
+                            }
+                        },
+                    }
+                }
+                if strategies.is_empty() {
+                    strategies.push(solver.strategy().clone());
+                }
+
+                let mut reports = Vec::new();
+                for strategy in strategies {
+                    config.strategy = Some(strategy);
+
+                    let spinner = Spinner::new("Running benchmark...");
+                    let report = benchmark(&solver, &config);
+                    spinner.stop();
+
+                    match report {
+                        Ok(report) if json => reports.push(report),
+                        Ok(report) => print_report(&report, config.hardest),
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                match reports.as_slice() {
+                    [] => {}
+                    [report] => println!("{}", report.to_json()),
+                    _ => println!("{}", serde_json::to_string_pretty(&reports).expect("reports serialize to JSON")),
+                }
             }
             "suggest" => {
-                let words = load_dictionary();
-                let solver = WordleSolver::new(words);
+                if let Some(tree) = options.load_tree() {
+                    println!("Best opening guess: {}", tree.next_guess().to_uppercase());
+                    return;
+                }
 
-                match solver.find_best_guess() {
+                let solver = options.create_solver();
+
+                match solver.next_guess() {
                     Some(analysis) => {
                         println!("Best opening guess: {}", analysis.word.to_uppercase());
                         println!("Entropy: {:.3} bits", analysis.entropy);
+                        if let Some(expected) = analysis.expected_guesses {
+                            println!("Expected guesses: {:.3}", expected);
+                        }
                     }
                     None => {
                         eprintln!("No words available.");
                     }
                 }
             }
+            "batch" => {
+                let mut targets_path = None;
+                let mut format = "csv".to_string();
+                let mut rest = args[2..].iter();
+                while let Some(arg) = rest.next() {
+                    match (arg.as_str(), rest.next()) {
+                        ("--targets", Some(value)) => targets_path = Some(PathBuf::from(value)),
+                        ("--format", Some(value)) if value == "csv" || value == "json" => format = value.clone(),
+                        _ => {
+                            eprintln!("Usage: wordle-bot batch [--targets <file>] [--format csv|json]");
+                            std::process::exit(1);
+                        }
+                    }
+                }
+
+                let (answers, guesses) = options.load_words();
+                let targets = match &targets_path {
+                    Some(path) => read_word_list(path, Some(answers[0].len())),
+                    None => answers.clone(),
+                };
+
+                let results = match options.load_tree() {
+                    Some(tree) => solve_batch(&targets, |target| tree.clone().solve_for_target(target)),
+                    None => {
+                        let solver = options.solver_for(answers, guesses);
+                        solve_batch(&targets, |target| solver.clone().solve_for_target(target))
+                    }
+                };
+                if format == "json" {
+                    println!("{}", batch_to_json(&results));
+                } else {
+                    print!("{}", batch_to_csv(&results));
+                }
+            }
+            "openers" => {
+                let json = args.iter().any(|arg| arg == "--json");
+                let words: Vec<&str> = args[2..]
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|arg| *arg != "--json")
+                    .collect();
+
+                let solver = options.create_solver();
+                let openers = match openers_to_explore(&solver, &words) {
+                    Ok(openers) => openers,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        eprintln!("Usage: wordle-bot openers [k | word...] [--json]");
+                        std::process::exit(1);
+                    }
+                };
+                let stats = match explore_with_progress(&solver, &openers) {
+                    Ok(stats) => stats,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                };
+
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&stats).expect("stats serialize to JSON"));
+                } else {
+                    print_openers(&solver, &stats);
+                }
+            }
+            "multi" => {
+                let usage = "Usage: wordle-bot multi <boards|dordle|quordle|octordle> [solve <word>...]";
+                let boards = match args.get(2).map(|arg| arg.to_lowercase()).as_deref() {
+                    Some("dordle") => 2,
+                    Some("quordle") => 4,
+                    Some("octordle") => 8,
+                    Some(n) => match n.parse::<usize>() {
+                        Ok(n) if n > 0 => n,
+                        _ => {
+                            eprintln!("{}", usage);
+                            std::process::exit(1);
+                        }
+                    },
+                    None => {
+                        eprintln!("{}", usage);
+                        std::process::exit(1);
+                    }
+                };
+
+                let solver = options.create_solver();
+                let mut game = match MultiBoardSolver::new(&solver, boards) {
+                    Ok(game) => game,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                };
+                if let Some(max_guesses) = options.max_guesses {
+                    game.set_max_guesses(max_guesses);
+                }
+
+                if args.get(3).map(String::as_str) != Some("solve") {
+                    run_multi_board(game);
+                    return;
+                }
+                let targets: Vec<String> = args[4..].iter().map(|arg| arg.to_lowercase()).collect();
+                let targets: Vec<&str> = targets.iter().map(String::as_str).collect();
+                let result = match game.solve_for_targets(&targets) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                };
+
+                for (i, (guess, patterns)) in result.guesses.iter().enumerate() {
+                    let tiles: Vec<String> = patterns
+                        .iter()
+                        // Solved boards are left blank, two columns per emoji tile
+                        .map(|pattern| pattern.map_or_else(|| "  ".repeat(game.board(0).word_length()), |p| p.to_string()))
+                        .collect();
+                    println!("Guess {:>2}: {} -> {}", i + 1, guess.to_uppercase(), tiles.join("  "));
+                }
+                println!();
+                match result.outcome {
+                    SolveOutcome::Solved => println!("Solved all {} boards in {} guesses.", boards, result.num_guesses()),
+                    SolveOutcome::Unsolved => println!(
+                        "Out of guesses: {} of {} boards solved.",
+                        result.solved_at.iter().flatten().count(),
+                        boards
+                    ),
+                    SolveOutcome::Stuck => println!("Stuck after {} guesses.", result.num_guesses()),
+                }
+            }
+            "absurdle" => {
+                let usage = "Usage: wordle-bot absurdle [solve] [--rule largest|evasive] [--breadth <n>] [--max <n>]";
+                let mut solve = false;
+                let mut config = AbsurdleConfig::default();
+                let mut rest = args[2..].iter();
+                while let Some(arg) = rest.next() {
+                    if arg == "solve" {
+                        solve = true;
+                        continue;
+                    }
+                    match (arg.as_str(), rest.next()) {
+                        ("--rule", Some(value)) if AdversaryRule::from_name(value).is_some() => {
+                            config.rule = AdversaryRule::from_name(value).unwrap();
+                        }
+                        ("--breadth", Some(value)) if value.parse::<usize>().is_ok_and(|n| n > 0) => {
+                            config.breadth = value.parse().unwrap();
+                        }
+                        ("--max", Some(value)) if value.parse::<usize>().is_ok_and(|n| n > 0) => {
+                            config.max_guesses = value.parse().unwrap();
+                        }
+                        _ => {
+                            eprintln!("{}", usage);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+
+                let solver = options.create_solver();
+                if !solve {
+                    play_absurdle(&solver, config.rule);
+                    return;
+                }
+
+                let spinner = Spinner::new("Searching...");
+                let steps = solve_absurdle(&solver, config);
+                spinner.stop();
+                match steps {
+                    Some(steps) => {
+                        println!(
+                            "Beats the {} adversary in {} guesses:",
+                            config.rule.name(),
+                            steps.len()
+                        );
+                        for (i, (guess, pattern)) in steps.iter().enumerate() {
+                            println!("Guess {}: {} -> {}", i + 1, guess.to_uppercase(), pattern);
+                        }
+                    }
+                    None => {
+                        println!(
+                            "No win found within {} guesses (breadth {}).",
+                            config.max_guesses, config.breadth
+                        );
+                    }
+                }
+            }
+            "tree" => {
+                if args.len() < 3 {
+                    eprintln!("Usage: wordle-bot tree <file>");
+                    std::process::exit(1);
+                }
+                let path = PathBuf::from(&args[2]);
+                let solver = options.create_solver();
+
+                let spinner = Spinner::new("Building decision tree...");
+                let start = std::time::Instant::now();
+                let tree = DecisionTree::from_strategy(&solver);
+                let elapsed = start.elapsed();
+                spinner.stop();
+
+                let tree = match tree {
+                    Some(tree) => tree,
+                    None => {
+                        eprintln!("The {} strategy does not solve every answer.", solver.strategy().name());
+                        std::process::exit(1);
+                    }
+                };
+                if let Err(e) = tree.save(&path) {
+                    eprintln!("Failed to write {}: {}", path.display(), e);
+                    std::process::exit(1);
+                }
+
+                println!("Strategy: {}", solver.strategy().name());
+                println!("Answers: {}", tree.num_answers());
+                println!("Average guesses: {:.3}", tree.average_guesses());
+                println!("Worst case: {} guesses", tree.max_depth());
+                println!("Time: {:.2?}", elapsed);
+                println!("Saved to {}", path.display());
+            }
             _ => {
                 eprintln!("Unknown command: {}", args[1]);
                 eprintln!("Use --help for usage information.");
@@ -389,7 +1529,7 @@ fn main() {
             }
         }
     } else {
-        run_interactive();
+        run_interactive(&options);
     }
 }
 
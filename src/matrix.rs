@@ -0,0 +1,321 @@
+//! Precomputed feedback patterns for every guess/answer pair.
+//!
+//! Computing feedback is the inner loop of both entropy scoring and candidate
+//! filtering. For a fixed word list the results never change, so the solver can
+//! build this table once and replace every `FeedbackPattern::calculate` call
+//! with a lookup.
+//!
+//! Patterns are stored as one byte per cell when every code fits (words of up
+//! to five letters) and as little-endian `u16`s otherwise.
+//!
+//! The table can also be written to disk and memory-mapped on later runs. The
+//! cache file is a fixed 32-byte little-endian header followed by the raw
+//! pattern cells, row by row:
+//!
+//! | offset | size | field                           |
+//! |--------|------|---------------------------------|
+//! | 0      | 8    | magic `WORDLEPM`                |
+//! | 8      | 4    | format version                  |
+//! | 12     | 4    | word length                     |
+//! | 16     | 4    | number of guesses               |
+//! | 20     | 4    | number of answers               |
+//! | 24     | 8    | FNV-1a hash of both word lists  |
+
+use crate::error::{validate_word, WordleError};
+use crate::feedback::FeedbackPattern;
+use crate::packed::PackedWord;
+use crate::MAX_WORD_LENGTH;
+use memmap2::Mmap;
+use rayon::prelude::*;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::ops::Deref;
+use std::path::Path;
+
+const MAGIC: &[u8; 8] = b"WORDLEPM";
+const FORMAT_VERSION: u32 = 1;
+const HEADER_LEN: usize = 32;
+
+/// Backing storage for the pattern table
+#[derive(Debug)]
+enum Storage {
+    Owned(Vec<u8>),
+    /// A mapped cache file; patterns start after the header
+    Mapped(Mmap),
+}
+
+impl Deref for Storage {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Storage::Owned(data) => data,
+            Storage::Mapped(map) => &map[HEADER_LEN..],
+        }
+    }
+}
+
+/// Where [`PatternMatrix::load_or_build`] got its table
+#[derive(Debug)]
+pub enum CacheStatus {
+    /// Mapped from the cache file
+    Loaded,
+    /// Built and written to the cache file
+    Built,
+    /// Built, but the cache file could not be written
+    Unsaved(io::Error),
+}
+
+impl CacheStatus {
+    /// Whether the table came from the cache file
+    pub fn is_cached(&self) -> bool {
+        matches!(self, CacheStatus::Loaded)
+    }
+}
+
+/// A dense `guess × answer` table of feedback patterns.
+///
+/// Row `g` holds the pattern produced by guess `g` against every answer, so
+/// scoring a single guess walks one contiguous slice.
+#[derive(Debug)]
+pub struct PatternMatrix {
+    word_length: usize,
+    num_guesses: usize,
+    num_answers: usize,
+    dictionary_hash: u64,
+    data: Storage,
+}
+
+impl PatternMatrix {
+    /// Build the table for the given word lists, one row per guess in parallel.
+    ///
+    /// Fails if a word is not `word_length` lowercase ASCII letters.
+    pub fn build(word_length: usize, guesses: &[String], answers: &[String]) -> Result<Self, WordleError> {
+        let pack = |words: &[String]| -> Result<Vec<PackedWord>, WordleError> {
+            words
+                .iter()
+                .map(|word| {
+                    validate_word(word, word_length)?;
+                    PackedWord::new(word)
+                })
+                .collect()
+        };
+        let packed_guesses = pack(guesses)?;
+        let packed_answers = pack(answers)?;
+
+        let num_answers = answers.len();
+        let width = cell_width(word_length);
+        let mut data = vec![0u8; guesses.len() * num_answers * width];
+
+        if num_answers > 0 {
+            data.par_chunks_mut(num_answers * width)
+                .zip(packed_guesses.par_iter())
+                .for_each(|(row, guess)| {
+                    for (cell, answer) in row.chunks_exact_mut(width).zip(&packed_answers) {
+                        let code = FeedbackPattern::calculate_packed(guess, answer).code();
+                        cell.copy_from_slice(&code.to_le_bytes()[..width]);
+                    }
+                });
+        }
+
+        Ok(Self {
+            word_length,
+            num_guesses: guesses.len(),
+            num_answers,
+            dictionary_hash: dictionary_hash(guesses, answers),
+            data: Storage::Owned(data),
+        })
+    }
+
+    /// Memory-map a cache file previously written by [`PatternMatrix::save`].
+    ///
+    /// Fails with `InvalidData` if the header is malformed (including table
+    /// sizes too large to address), was written by a different format
+    /// version, or the file is truncated.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: cache files are only ever replaced via rename, never
+        // modified in place, so the mapping stays valid while we hold it.
+        let map = unsafe { Mmap::map(&file)? };
+
+        if map.len() < HEADER_LEN || &map[0..8] != MAGIC {
+            return Err(invalid_data("not a pattern matrix cache file"));
+        }
+        if read_u32(&map, 8) != FORMAT_VERSION {
+            return Err(invalid_data("unsupported cache format version"));
+        }
+        let word_length = read_u32(&map, 12) as usize;
+        if word_length == 0 || word_length > MAX_WORD_LENGTH {
+            return Err(invalid_data("unsupported word length in cache header"));
+        }
+
+        let num_guesses = read_u32(&map, 16) as usize;
+        let num_answers = read_u32(&map, 20) as usize;
+        let dictionary_hash = u64::from_le_bytes(map[24..32].try_into().unwrap());
+
+        let data_len = num_guesses
+            .checked_mul(num_answers)
+            .and_then(|cells| cells.checked_mul(cell_width(word_length)))
+            .ok_or_else(|| invalid_data("table size in cache header overflows"))?;
+        if map.len() - HEADER_LEN != data_len {
+            return Err(invalid_data("cache file is truncated"));
+        }
+
+        Ok(Self {
+            word_length,
+            num_guesses,
+            num_answers,
+            dictionary_hash,
+            data: Storage::Mapped(map),
+        })
+    }
+
+    /// Write the table to `path`.
+    ///
+    /// The file is written next to its destination and renamed into place, so
+    /// a process that already has the old cache mapped is unaffected.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            if !dir.as_os_str().is_empty() {
+                fs::create_dir_all(dir)?;
+            }
+        }
+
+        let mut header = [0u8; HEADER_LEN];
+        header[0..8].copy_from_slice(MAGIC);
+        header[8..12].copy_from_slice(&FORMAT_VERSION.to_le_bytes());
+        header[12..16].copy_from_slice(&(self.word_length as u32).to_le_bytes());
+        header[16..20].copy_from_slice(&(self.num_guesses as u32).to_le_bytes());
+        header[20..24].copy_from_slice(&(self.num_answers as u32).to_le_bytes());
+        header[24..32].copy_from_slice(&self.dictionary_hash.to_le_bytes());
+
+        let tmp_path = path.with_extension(format!("tmp{}", std::process::id()));
+        let result = (|| {
+            let mut file = File::create(&tmp_path)?;
+            file.write_all(&header)?;
+            file.write_all(&self.data)?;
+            file.sync_all()?;
+            fs::rename(&tmp_path, path)
+        })();
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+        result
+    }
+
+    /// Map the cache at `path` if it matches these word lists, otherwise build
+    /// the table and write it there.
+    ///
+    /// Returns the matrix and where it came from. A cache that cannot be
+    /// written is not an error: the table is still returned, with the write
+    /// error in [`CacheStatus::Unsaved`].
+    /// Fails as [`PatternMatrix::build`] does.
+    pub fn load_or_build(
+        path: &Path,
+        word_length: usize,
+        guesses: &[String],
+        answers: &[String],
+    ) -> Result<(Self, CacheStatus), WordleError> {
+        if let Ok(cached) = Self::open(path) {
+            if cached.word_length == word_length && cached.matches(guesses, answers) {
+                return Ok((cached, CacheStatus::Loaded));
+            }
+        }
+
+        let matrix = Self::build(word_length, guesses, answers)?;
+        let status = match matrix.save(path) {
+            Ok(()) => CacheStatus::Built,
+            Err(e) => CacheStatus::Unsaved(e),
+        };
+        Ok((matrix, status))
+    }
+
+    /// Whether this table was built from exactly these word lists
+    pub fn matches(&self, guesses: &[String], answers: &[String]) -> bool {
+        self.num_guesses == guesses.len()
+            && self.num_answers == answers.len()
+            && self.dictionary_hash == dictionary_hash(guesses, answers)
+    }
+
+    pub fn word_length(&self) -> usize {
+        self.word_length
+    }
+
+    pub fn num_guesses(&self) -> usize {
+        self.num_guesses
+    }
+
+    pub fn num_answers(&self) -> usize {
+        self.num_answers
+    }
+
+    pub fn dictionary_hash(&self) -> u64 {
+        self.dictionary_hash
+    }
+
+    /// Whether the table is backed by a memory-mapped cache file
+    pub fn is_mapped(&self) -> bool {
+        matches!(self.data, Storage::Mapped(_))
+    }
+
+    /// Feedback for guess `guess` against answer `answer` (both indices).
+    /// Both must be in range.
+    #[inline]
+    pub fn get(&self, guess: usize, answer: usize) -> FeedbackPattern {
+        debug_assert!(guess < self.num_guesses && answer < self.num_answers);
+        let cell = guess * self.num_answers + answer;
+        let code = if cell_width(self.word_length) == 1 {
+            self.data[cell] as u16
+        } else {
+            u16::from_le_bytes([self.data[2 * cell], self.data[2 * cell + 1]])
+        };
+        FeedbackPattern::from_code(code, self.word_length)
+    }
+}
+
+/// Bytes per stored pattern for words of length `word_length`
+fn cell_width(word_length: usize) -> usize {
+    if FeedbackPattern::num_patterns(word_length) <= 256 {
+        1
+    } else {
+        2
+    }
+}
+
+/// Stable FNV-1a hash over both word lists, in order.
+///
+/// `std`'s `DefaultHasher` is not guaranteed to be stable across Rust
+/// releases, which would silently invalidate every cache file on upgrade.
+pub fn dictionary_hash(guesses: &[String], answers: &[String]) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET;
+    let mut feed = |bytes: &[u8]| {
+        for &b in bytes {
+            hash ^= b as u64;
+            hash = hash.wrapping_mul(PRIME);
+        }
+    };
+
+    for word in guesses {
+        feed(word.as_bytes());
+        feed(b"\n");
+    }
+    // Separator so moving a word between the lists changes the hash
+    feed(b"\0");
+    for word in answers {
+        feed(word.as_bytes());
+        feed(b"\n");
+    }
+
+    hash
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,338 @@
+//! Playing several boards at once (Dordle, Quordle, Octordle).
+//!
+//! Every guess is played on all boards still unsolved, each hiding its own
+//! answer. A [`MultiBoardSolver`] keeps one [`WordleSolver`] per board, all
+//! sharing the same word lists, and ranks guesses by the guesses still
+//! expected to finish every board, then by the information gained across them.
+//! Games allow [`MAX_GUESSES`] guesses plus one per extra board: 7 for two
+//! boards, 9 for four and 13 for eight.
+
+use crate::error::{validate_word, WordleError};
+use crate::feedback::FeedbackPattern;
+use crate::lookahead::estimated_guesses;
+use crate::solver::{SolveOutcome, WordleSolver};
+use crate::MAX_GUESSES;
+use rayon::prelude::*;
+
+/// Guesses allowed for a game of `boards` boards
+pub fn default_max_guesses(boards: usize) -> usize {
+    MAX_GUESSES - 1 + boards
+}
+
+/// Result of analyzing a guess on every unsolved board
+#[derive(Debug, Clone)]
+pub struct MultiGuessAnalysis {
+    pub word: String,
+    /// Expected information gained, summed over the unsolved boards
+    pub entropy: f64,
+    /// Expected candidates left after the guess, summed over the unsolved boards
+    pub expected_remaining: f64,
+    /// Estimated guesses needed after this one to finish every unsolved
+    /// board. Guesses are shared, so this is the most any one board is
+    /// expected to need, but at least one per board the guess leaves
+    /// unsolved, since each answer takes a guess of its own.
+    pub expected_guesses: f64,
+    /// Expected number of boards this guess solves
+    pub expected_solved: f64,
+}
+
+/// What one guess is expected to do to one board
+#[derive(Debug, Clone, Copy, Default)]
+struct Outlook {
+    entropy: f64,
+    expected_remaining: f64,
+    expected_guesses: f64,
+    solve_probability: f64,
+}
+
+impl Outlook {
+    fn of(board: &WordleSolver, guess: usize) -> Self {
+        let num_patterns = FeedbackPattern::num_patterns(board.word_length());
+        let mut counts = vec![0usize; num_patterns];
+        let mut weights = vec![0.0; num_patterns];
+        for a in board.candidates().iter() {
+            let pattern = board.pattern_for(guess, a).index();
+            counts[pattern] += 1;
+            weights[pattern] += board.answer_weight(a);
+        }
+        let total: f64 = weights.iter().sum();
+        let win = FeedbackPattern::all_correct(board.word_length()).index();
+
+        let mut outlook = Outlook {
+            solve_probability: weights[win] / total,
+            ..Outlook::default()
+        };
+        for (pattern, (&count, &weight)) in counts.iter().zip(&weights).enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let p = weight / total;
+            outlook.entropy -= p * p.log2();
+            outlook.expected_remaining += p * count as f64;
+            if pattern != win {
+                outlook.expected_guesses += p * estimated_guesses(count);
+            }
+        }
+        outlook
+    }
+}
+
+/// The guesses made by an automatic multi-board solve and how it ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiSolveResult {
+    /// Every guess with the feedback from each board, `None` once a board
+    /// was solved
+    pub guesses: Vec<(String, Vec<Option<FeedbackPattern>>)>,
+    /// Guess number (1-based) that solved each board, if any
+    pub solved_at: Vec<Option<usize>>,
+    pub outcome: SolveOutcome,
+}
+
+impl MultiSolveResult {
+    pub fn is_solved(&self) -> bool {
+        self.outcome == SolveOutcome::Solved
+    }
+
+    pub fn num_guesses(&self) -> usize {
+        self.guesses.len()
+    }
+}
+
+/// A solver for several boards played with the same guesses
+#[derive(Debug, Clone)]
+pub struct MultiBoardSolver {
+    boards: Vec<WordleSolver>,
+    /// Guess number (1-based) that solved each board
+    solved_at: Vec<Option<usize>>,
+    /// Guesses made so far, oldest first
+    guesses: Vec<String>,
+    /// Guesses allowed, or `None` for no limit
+    max_guesses: Option<usize>,
+}
+
+impl MultiBoardSolver {
+    /// Start a game of `boards` boards, each with every one of `solver`'s
+    /// answers possible. Its strategy and hard mode rules are not used.
+    ///
+    /// Fails with [`WordleError::NoBoards`] if `boards` is zero.
+    pub fn new(solver: &WordleSolver, boards: usize) -> Result<Self, WordleError> {
+        if boards == 0 {
+            return Err(WordleError::NoBoards);
+        }
+        let mut board = solver.clone();
+        board.reset();
+        Ok(Self {
+            boards: vec![board; boards],
+            solved_at: vec![None; boards],
+            guesses: Vec::new(),
+            max_guesses: Some(default_max_guesses(boards)),
+        })
+    }
+
+    pub fn num_boards(&self) -> usize {
+        self.boards.len()
+    }
+
+    /// The solver tracking board `board` (0-based)
+    pub fn board(&self, board: usize) -> &WordleSolver {
+        &self.boards[board]
+    }
+
+    pub fn is_solved(&self, board: usize) -> bool {
+        self.solved_at[board].is_some()
+    }
+
+    /// Guess number (1-based) that solved each board
+    pub fn solved_at(&self) -> &[Option<usize>] {
+        &self.solved_at
+    }
+
+    /// Indices of the boards not solved yet
+    pub fn unsolved_boards(&self) -> Vec<usize> {
+        (0..self.boards.len()).filter(|&b| !self.is_solved(b)).collect()
+    }
+
+    pub fn all_solved(&self) -> bool {
+        self.solved_at.iter().all(Option::is_some)
+    }
+
+    /// Guesses made so far, oldest first
+    pub fn guesses(&self) -> &[String] {
+        &self.guesses
+    }
+
+    /// Change the guess budget ([`default_max_guesses`] by default), or
+    /// remove the limit with `None`
+    pub fn set_max_guesses(&mut self, max_guesses: Option<usize>) {
+        self.max_guesses = max_guesses;
+    }
+
+    pub fn max_guesses(&self) -> Option<usize> {
+        self.max_guesses
+    }
+
+    /// Guesses still allowed, or `None` without a limit
+    pub fn guesses_left(&self) -> Option<usize> {
+        self.max_guesses.map(|max| max.saturating_sub(self.guesses.len()))
+    }
+
+    /// Start over with every board unsolved
+    pub fn reset(&mut self) {
+        for board in &mut self.boards {
+            board.reset();
+        }
+        self.solved_at.fill(None);
+        self.guesses.clear();
+    }
+
+    /// Record `guess` with one pattern per unsolved board, in board order.
+    ///
+    /// Fails without changing anything if the guess is malformed, the number
+    /// of patterns is wrong, or a board rejects its feedback.
+    pub fn apply_feedback(&mut self, guess: &str, patterns: &[FeedbackPattern]) -> Result<(), WordleError> {
+        validate_word(guess, self.boards[0].word_length())?;
+        let unsolved = self.unsolved_boards();
+        if patterns.len() != unsolved.len() {
+            return Err(WordleError::FeedbackCount {
+                given: patterns.len(),
+                expected: unsolved.len(),
+            });
+        }
+
+        let mut updated = Vec::with_capacity(unsolved.len());
+        for (&b, &pattern) in unsolved.iter().zip(patterns) {
+            let mut board = self.boards[b].clone();
+            board.apply_feedback(guess, pattern).map_err(|error| WordleError::OnBoard {
+                board: b,
+                error: Box::new(error),
+            })?;
+            updated.push(board);
+        }
+
+        self.guesses.push(guess.to_string());
+        for ((b, board), pattern) in unsolved.into_iter().zip(updated).zip(patterns) {
+            self.boards[b] = board;
+            if pattern.is_win() {
+                self.solved_at[b] = Some(self.guesses.len());
+            }
+        }
+        Ok(())
+    }
+
+    /// Rank every guess for the unsolved boards, best first, returning at most `n`
+    pub fn find_best_guesses(&self, n: usize) -> Vec<MultiGuessAnalysis> {
+        let unsolved = self.unsolved_boards();
+        if unsolved.iter().any(|&b| self.boards[b].remaining_count() == 0) {
+            return Vec::new();
+        }
+
+        // Boards left with the same candidates score every guess alike
+        let mut groups: Vec<(usize, f64)> = Vec::new();
+        for &b in &unsolved {
+            let same = groups
+                .iter_mut()
+                .find(|(other, _)| self.boards[*other].candidates() == self.boards[b].candidates());
+            match same {
+                Some((_, copies)) => *copies += 1.0,
+                None => groups.push((b, 1.0)),
+            }
+        }
+
+        let first = &self.boards[0];
+        let mut analyses: Vec<MultiGuessAnalysis> = (0..first.all_words().len())
+            .into_par_iter()
+            .map(|g| {
+                let mut analysis = MultiGuessAnalysis {
+                    word: first.word(g).to_string(),
+                    entropy: 0.0,
+                    expected_remaining: 0.0,
+                    expected_guesses: 0.0,
+                    expected_solved: 0.0,
+                };
+                let mut slowest_board: f64 = 0.0;
+                for &(b, copies) in &groups {
+                    let outlook = Outlook::of(&self.boards[b], g);
+                    analysis.entropy += copies * outlook.entropy;
+                    analysis.expected_remaining += copies * outlook.expected_remaining;
+                    analysis.expected_solved += copies * outlook.solve_probability;
+                    slowest_board = slowest_board.max(outlook.expected_guesses);
+                }
+                let boards_left = unsolved.len() as f64 - analysis.expected_solved;
+                analysis.expected_guesses = slowest_board.max(boards_left);
+                analysis
+            })
+            .collect();
+
+        analyses.sort_by(|a, b| {
+            a.expected_guesses
+                .total_cmp(&b.expected_guesses)
+                .then(b.entropy.total_cmp(&a.entropy))
+                .then(b.expected_solved.total_cmp(&a.expected_solved))
+                .then(a.word.cmp(&b.word))
+        });
+        analyses.truncate(n);
+        analyses
+    }
+
+    pub fn find_best_guess(&self) -> Option<MultiGuessAnalysis> {
+        self.find_best_guesses(1).into_iter().next()
+    }
+
+    /// Play automatically against `targets`, one per board, until every board
+    /// is solved, the guess budget runs out or no guess makes progress.
+    ///
+    /// Every target must be one of the solver's answers.
+    pub fn solve_for_targets(&mut self, targets: &[&str]) -> Result<MultiSolveResult, WordleError> {
+        if targets.len() != self.boards.len() {
+            return Err(WordleError::TargetCount {
+                given: targets.len(),
+                expected: self.boards.len(),
+            });
+        }
+        for target in targets {
+            validate_word(target, self.boards[0].word_length())?;
+            if self.boards[0].answer_index_of(target).is_none() {
+                return Err(WordleError::NotInDictionary(target.to_string()));
+            }
+        }
+
+        let mut guesses = Vec::new();
+        let outcome = loop {
+            if self.all_solved() {
+                break SolveOutcome::Solved;
+            }
+            if self.guesses_left() == Some(0) {
+                break SolveOutcome::Unsolved;
+            }
+            let best = match self.find_best_guess() {
+                Some(best) => best,
+                None => break SolveOutcome::Stuck,
+            };
+
+            let unsolved = self.unsolved_boards();
+            let mut feedback = vec![None; self.boards.len()];
+            for &b in &unsolved {
+                feedback[b] = Some(FeedbackPattern::try_calculate(&best.word, targets[b])?);
+            }
+            let patterns: Vec<FeedbackPattern> = feedback.iter().flatten().copied().collect();
+
+            let before: usize = unsolved.iter().map(|&b| self.boards[b].remaining_count()).sum();
+            self.apply_feedback(&best.word, &patterns)?;
+            guesses.push((best.word, feedback));
+            let after: usize = unsolved
+                .iter()
+                .filter(|&&b| !self.is_solved(b))
+                .map(|&b| self.boards[b].remaining_count())
+                .sum();
+            if after == before {
+                break SolveOutcome::Stuck;
+            }
+        };
+
+        Ok(MultiSolveResult {
+            guesses,
+            solved_at: self.solved_at.clone(),
+            outcome,
+        })
+    }
+}
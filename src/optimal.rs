@@ -0,0 +1,236 @@
+//! Exact optimal decision trees.
+//!
+//! A strategy is fully described by a [`DecisionTree`]. This module finds the
+//! tree minimizing the total number of guesses over every remaining answer,
+//! optionally requiring each answer to be found within a maximum number of
+//! guesses.
+//!
+//! The search is a depth-first branch and bound over [`WordleSolver::partition`]:
+//! guesses are tried in order of a lower bound on their cost and abandoned as
+//! soon as that bound reaches the best tree found so far. Results are memoized
+//! on the set of remaining answers, since many guess sequences lead to the same
+//! set. The search is exponential and only practical once the candidates have
+//! been narrowed down, or for small word lists.
+
+use crate::cache::DecisionKey;
+use crate::constraints::HardModeConstraints;
+use crate::feedback::FeedbackPattern;
+use crate::solver::WordleSolver;
+use crate::tree::DecisionTree;
+use std::collections::{HashMap, HashSet};
+
+/// Limits for the optimal search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptimalConfig {
+    /// Most guesses allowed to find any answer, or `None` for no limit
+    pub max_depth: Option<usize>,
+    /// Only expand this many of the most promising guesses at each node.
+    /// The result is no longer guaranteed to be optimal when set.
+    pub breadth: Option<usize>,
+}
+
+impl Default for OptimalConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: Some(6),
+            breadth: None,
+        }
+    }
+}
+
+/// Find an optimal decision tree for the solver's remaining candidates.
+///
+/// Guesses follow the solver's hard mode rules. Returns `None` if there are no
+/// candidates, or if they cannot all be found within `config.max_depth`.
+pub fn optimal_tree(solver: &WordleSolver, config: OptimalConfig) -> Option<DecisionTree> {
+    let mut search = Search {
+        solver,
+        config,
+        memo: HashMap::new(),
+    };
+    let depth = config.max_depth.unwrap_or(usize::MAX);
+    let candidates = &solver.candidate_indices();
+    search.solve(candidates, solver.constraints(), depth, usize::MAX)?;
+    Some(search.tree(candidates, solver.constraints(), depth))
+}
+
+/// Fewest total guesses any tree can use to find `n` answers: one answer on
+/// the first guess and every other one on the second
+pub(crate) fn lower_bound(n: usize) -> usize {
+    (2 * n).saturating_sub(1)
+}
+
+/// Whether [`lower_bound`] is reached for `n` answers without searching: with
+/// two or fewer, guessing either one leaves at most the other
+pub(crate) fn lower_bound_is_exact(n: usize) -> bool {
+    n <= 2
+}
+
+/// What the search has learned about one node
+#[derive(Debug, Clone, Copy)]
+enum Bound {
+    /// The optimal total and the guess achieving it
+    Exact(usize, usize),
+    /// The optimal total is at least this
+    AtLeast(usize),
+}
+
+struct Search<'a> {
+    solver: &'a WordleSolver,
+    config: OptimalConfig,
+    /// Bounds for each node, keyed by its state and remaining depth
+    memo: HashMap<(DecisionKey, usize), Bound>,
+}
+
+/// A guess considered at one node, with its non-winning buckets largest first
+struct Choice {
+    guess: usize,
+    lower_bound: usize,
+    buckets: Vec<(FeedbackPattern, Vec<usize>)>,
+}
+
+impl Search<'_> {
+    /// Optimal total guesses and first guess for `candidates` with at most
+    /// `depth` guesses, or `None` if no tree does better than `bound`
+    fn solve(
+        &mut self,
+        candidates: &[usize],
+        constraints: &HardModeConstraints,
+        depth: usize,
+        bound: usize,
+    ) -> Option<(usize, usize)> {
+        let n = candidates.len();
+        if n == 0 || depth == 0 || lower_bound(n) >= bound || (depth == 1 && n > 1) {
+            return None;
+        }
+        if lower_bound_is_exact(n) {
+            return Some((lower_bound(n), self.solver.guess_index_of_answer(candidates[0])));
+        }
+
+        let key = (DecisionKey::of_indices(self.solver, candidates, constraints), depth);
+        match self.memo.get(&key) {
+            Some(&Bound::Exact(total, guess)) => return (total < bound).then_some((total, guess)),
+            Some(&Bound::AtLeast(total)) if total >= bound => return None,
+            _ => {}
+        }
+
+        let mut best: Option<(usize, usize)> = None;
+        let mut limit = bound;
+        for option in self.options(candidates, constraints, depth) {
+            if option.lower_bound >= limit {
+                break;
+            }
+
+            let mut cost = option.lower_bound;
+            for (pattern, bucket) in &option.buckets {
+                if lower_bound_is_exact(bucket.len()) {
+                    continue;
+                }
+                let mut next = constraints.clone();
+                next.update(self.solver.word(option.guess), *pattern);
+
+                let slack = limit - (cost - lower_bound(bucket.len()));
+                match self.solve(bucket, &next, depth - 1, slack) {
+                    Some((total, _)) => cost += total - lower_bound(bucket.len()),
+                    None => {
+                        cost = limit;
+                        break;
+                    }
+                }
+            }
+
+            if cost < limit {
+                limit = cost;
+                best = Some((cost, option.guess));
+            }
+        }
+
+        let entry = match best {
+            Some((total, guess)) => Bound::Exact(total, guess),
+            None => Bound::AtLeast(bound),
+        };
+        self.memo.insert(key, entry);
+        best
+    }
+
+    /// Guesses worth trying at a node, most promising first
+    fn options(&self, candidates: &[usize], constraints: &HardModeConstraints, depth: usize) -> Vec<Choice> {
+        let n = candidates.len();
+        let is_candidate = self.solver.candidate_mask(candidates);
+        // Without hard mode, guesses splitting the candidates identically
+        // lead to identical subtrees
+        let dedupe = !self.solver.hard_mode_rules().is_enabled();
+
+        let mut seen: HashSet<Vec<u16>> = HashSet::new();
+        let mut options: Vec<Choice> = Vec::new();
+        for guess in self.solver.valid_guesses_under(constraints) {
+            if dedupe {
+                let signature = candidates
+                    .iter()
+                    .map(|&a| self.solver.pattern_for(guess, a).code())
+                    .collect();
+                if !seen.insert(signature) {
+                    continue;
+                }
+            }
+
+            let mut buckets: Vec<_> = self
+                .solver
+                .partition(guess, candidates)
+                .into_iter()
+                .filter(|(pattern, _)| !pattern.is_win())
+                .collect();
+            // No progress, or some answer could not be found in time
+            if buckets.iter().any(|(_, bucket)| bucket.len() == n || (depth == 2 && bucket.len() > 1)) {
+                continue;
+            }
+
+            buckets.sort_by_key(|(_, bucket)| std::cmp::Reverse(bucket.len()));
+            let lower_bound = n + buckets.iter().map(|(_, bucket)| lower_bound(bucket.len())).sum::<usize>();
+            options.push(Choice {
+                guess,
+                lower_bound,
+                buckets,
+            });
+        }
+
+        options.sort_by(|a, b| {
+            a.lower_bound
+                .cmp(&b.lower_bound)
+                .then(is_candidate[b.guess].cmp(&is_candidate[a.guess]))
+                .then(b.buckets.len().cmp(&a.buckets.len()))
+        });
+        if let Some(breadth) = self.config.breadth {
+            options.truncate(breadth);
+        }
+        options
+    }
+
+    /// Rebuild the tree for a node already solved by [`Search::solve`]
+    fn tree(&mut self, candidates: &[usize], constraints: &HardModeConstraints, depth: usize) -> DecisionTree {
+        let (_, guess) = self
+            .solve(candidates, constraints, depth, usize::MAX)
+            .expect("node was already solved");
+        let is_candidate = candidates
+            .iter()
+            .any(|&a| self.solver.guess_index_of_answer(a) == guess);
+
+        let children = self
+            .solver
+            .partition(guess, candidates)
+            .into_iter()
+            .filter(|(pattern, _)| !pattern.is_win())
+            .map(|(pattern, bucket)| {
+                let mut next = constraints.clone();
+                next.update(self.solver.word(guess), pattern);
+                (pattern, self.tree(&bucket, &next, depth - 1))
+            })
+            .collect();
+
+        DecisionTree {
+            guess: self.solver.word(guess).to_string(),
+            is_answer: is_candidate,
+            children,
+        }
+    }
+}
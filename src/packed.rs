@@ -0,0 +1,163 @@
+//! Words packed into machine integers for fast feedback.
+//!
+//! [`FeedbackPattern::try_calculate`] walks both words byte by byte and counts
+//! letters in a 26-entry array. A [`PackedWord`] instead keeps its letters as
+//! 5-bit fields of a `u64`, its letter counts as 4-bit fields of a `u128` and
+//! the set of letters it uses as a bitmask, all computed once per word.
+//! [`FeedbackPattern::calculate_packed`] then finds every green at once by
+//! comparing whole words, skips words sharing no letters outright, finds the
+//! yellows of guesses without repeated letters from the bitmask alone, and
+//! otherwise tracks the letters left for yellows in a single register.
+
+use crate::error::{validate_word, WordleError};
+use crate::feedback::FeedbackPattern;
+use crate::MAX_WORD_LENGTH;
+
+/// Bits per letter in [`PackedWord::letters`]
+const LETTER_BITS: u32 = 5;
+
+/// Bits per letter count in [`PackedWord::counts`]
+const COUNT_BITS: u32 = 4;
+
+/// The low four bits of every letter field
+const LOW_BITS: u64 = repeat_field(0b01111);
+
+/// The top bit of every letter field
+const HIGH_BITS: u64 = repeat_field(0b10000);
+
+/// `value` copied into each of the [`MAX_WORD_LENGTH`] letter fields
+const fn repeat_field(value: u64) -> u64 {
+    let mut packed = 0;
+    let mut i = 0;
+    while i < MAX_WORD_LENGTH {
+        packed |= value << (i as u32 * LETTER_BITS);
+        i += 1;
+    }
+    packed
+}
+
+/// Base-3 place value of each position in a pattern code
+const POWERS_OF_3: [u16; MAX_WORD_LENGTH] = {
+    let mut powers = [1; MAX_WORD_LENGTH];
+    let mut i = 1;
+    while i < MAX_WORD_LENGTH {
+        powers[i] = powers[i - 1] * 3;
+        i += 1;
+    }
+    powers
+};
+
+/// A word of up to [`MAX_WORD_LENGTH`] lowercase ASCII letters, precomputed
+/// for [`FeedbackPattern::calculate_packed`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedWord {
+    /// Letter `i` (0 for `a`) in bits `5i..5i + 5`
+    letters: u64,
+    /// How many times letter `c` occurs, in bits `4c..4c + 4`
+    counts: u128,
+    /// Bit `c` set if letter `c` occurs
+    mask: u32,
+    len: u8,
+}
+
+impl PackedWord {
+    /// Pack `word`, failing unless it is lowercase ASCII letters and at most
+    /// [`MAX_WORD_LENGTH`] long
+    pub fn new(word: &str) -> Result<Self, WordleError> {
+        validate_word(word, word.len().min(MAX_WORD_LENGTH))?;
+        let bytes = word.as_bytes();
+
+        let mut packed = Self {
+            letters: 0,
+            counts: 0,
+            mask: 0,
+            len: bytes.len() as u8,
+        };
+        for (i, &byte) in bytes.iter().enumerate() {
+            let letter = (byte - b'a') as u32;
+            packed.letters |= (letter as u64) << (i as u32 * LETTER_BITS);
+            packed.counts += 1 << (letter * COUNT_BITS);
+            packed.mask |= 1 << letter;
+        }
+        Ok(packed)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Letter index (0 for `a`) at `position`
+    #[inline]
+    fn letter(&self, position: usize) -> u32 {
+        ((self.letters >> (position as u32 * LETTER_BITS)) & 0b11111) as u32
+    }
+
+    /// Bitmask with the top bit of each field where this word and `other`
+    /// have the same letter
+    #[inline]
+    fn matching_fields(&self, other: &PackedWord) -> u64 {
+        let diff = self.letters ^ other.letters;
+        // A field is nonzero if its top bit is set, or if adding 0b01111 to
+        // its low four bits carries into the top bit
+        let nonzero = (((diff & LOW_BITS) + LOW_BITS) | diff) & HIGH_BITS;
+        let used = HIGH_BITS & ((1 << (self.len as u32 * LETTER_BITS)) - 1);
+        !nonzero & used
+    }
+}
+
+impl FeedbackPattern {
+    /// Feedback for `guess` against `target`, equal to
+    /// [`FeedbackPattern::try_calculate`] on the unpacked words.
+    ///
+    /// Both words must be the same length.
+    pub fn calculate_packed(guess: &PackedWord, target: &PackedWord) -> Self {
+        let len = guess.len();
+        debug_assert_eq!(len, target.len());
+
+        if guess.mask & target.mask == 0 {
+            return Self::from_code(0, len);
+        }
+
+        let greens = guess.matching_fields(target);
+        let is_green = |position: usize| greens & (0b10000 << (position as u32 * LETTER_BITS)) != 0;
+        let mut code = 0;
+
+        if guess.mask.count_ones() as usize == len {
+            // Each letter of the guess appears once, so no green can use up a
+            // letter a yellow elsewhere needs: every other shared letter is yellow
+            for (position, &place) in POWERS_OF_3.iter().enumerate().take(len) {
+                if is_green(position) {
+                    code += 2 * place;
+                } else if target.mask & (1 << guess.letter(position)) != 0 {
+                    code += place;
+                }
+            }
+            return Self::from_code(code, len);
+        }
+
+        // Letters of the target not matched by a green, available for yellows
+        let mut remaining = target.counts;
+        for (position, &place) in POWERS_OF_3.iter().enumerate().take(len) {
+            if is_green(position) {
+                remaining -= 1 << (guess.letter(position) * COUNT_BITS);
+                code += 2 * place;
+            }
+        }
+        for (position, &place) in POWERS_OF_3.iter().enumerate().take(len) {
+            if is_green(position) {
+                continue;
+            }
+            let shift = guess.letter(position) * COUNT_BITS;
+            if (remaining >> shift) & 0b1111 != 0 {
+                remaining -= 1 << shift;
+                code += place;
+            }
+        }
+
+        Self::from_code(code, len)
+    }
+}
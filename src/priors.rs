@@ -0,0 +1,99 @@
+//! Prior answer likelihoods from word frequencies.
+//!
+//! Without priors every remaining word is considered equally likely to be the
+//! answer. A frequency list lets common words outweigh obscure ones: each
+//! answer's weight is proportional to its count, and entropy, the chance a
+//! guess wins and the posterior of each remaining word all use those weights.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Relative frequency of each word
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WordPriors {
+    counts: HashMap<String, f64>,
+    /// Weight given to words missing from the list (and to zero counts):
+    /// the smallest positive count, so unknown words rank as the rarest
+    floor: f64,
+}
+
+impl WordPriors {
+    /// Build priors from `(word, count)` pairs. Counts must be finite and
+    /// non-negative; later duplicates overwrite earlier ones.
+    pub fn from_counts<I, S>(counts: I) -> Self
+    where
+        I: IntoIterator<Item = (S, f64)>,
+        S: AsRef<str>,
+    {
+        let counts: HashMap<String, f64> = counts
+            .into_iter()
+            .map(|(word, count)| (word.as_ref().to_lowercase(), count))
+            .collect();
+        let floor = counts
+            .values()
+            .copied()
+            .filter(|&count| count > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        Self {
+            counts,
+            floor: if floor.is_finite() { floor } else { 1.0 },
+        }
+    }
+
+    /// Parse a frequency list with one `word<TAB>count` entry per line.
+    ///
+    /// Any whitespace may separate the two columns. Blank lines and lines
+    /// starting with `#` are skipped.
+    pub fn parse(text: &str) -> io::Result<Self> {
+        let mut counts = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = |message: String| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", i + 1, message))
+            };
+            let mut columns = line.split_whitespace();
+            let (word, count) = match (columns.next(), columns.next(), columns.next()) {
+                (Some(word), Some(count), None) => (word, count),
+                _ => return Err(invalid("expected a word and a count".to_string())),
+            };
+            let count: f64 = match count.parse() {
+                Ok(count) if f64::is_finite(count) && count >= 0.0 => count,
+                _ => return Err(invalid(format!("invalid count '{}'", count))),
+            };
+            counts.push((word, count));
+        }
+        Ok(Self::from_counts(counts))
+    }
+
+    /// Read a frequency list from a file (see [`WordPriors::parse`])
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Unnormalized weight of `word`, never zero
+    pub fn weight(&self, word: &str) -> f64 {
+        match self.counts.get(word) {
+            Some(&count) if count > 0.0 => count,
+            _ => self.floor,
+        }
+    }
+
+    /// Whether the list has an entry for `word`
+    pub fn contains(&self, word: &str) -> bool {
+        self.counts.contains_key(word)
+    }
+
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+}
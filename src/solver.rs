@@ -5,9 +5,21 @@
 //! (entropy) from each guess, which minimizes the expected number of remaining
 //! possible words.
 
-use crate::feedback::{Feedback, FeedbackPattern};
-use crate::WORD_LENGTH;
+use crate::bitset::CandidateSet;
+use crate::cache::{DecisionCache, DecisionKey};
+use crate::constraints::{HardMode, HardModeConstraints};
+use crate::error::{validate_word, WordleError};
+use crate::feedback::FeedbackPattern;
+use crate::matrix::{CacheStatus, PatternMatrix};
+use crate::packed::PackedWord;
+use crate::priors::WordPriors;
+use crate::strategy::{EntropyStrategy, GuessStrategy};
+use crate::{MAX_GUESSES, WORD_LENGTH};
 use rayon::prelude::*;
+use serde::{Serialize, Serializer};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
 
 /// Result of analyzing a potential guess
 #[derive(Debug, Clone)]
@@ -16,211 +28,752 @@ pub struct GuessAnalysis {
     pub entropy: f64,
     pub expected_remaining: f64,
     pub is_possible_answer: bool,
+    /// Expected total guesses to finish, when the strategy computes it
+    pub expected_guesses: Option<f64>,
+    /// Size of the largest feedback bucket (candidates left in the worst case)
+    pub worst_case_remaining: usize,
+    /// Number of distinct feedback patterns the guess can produce
+    pub bucket_count: usize,
+    /// Chance the guess is the answer, given the remaining candidates
+    pub answer_probability: f64,
 }
 
-/// Hard mode constraints from previous guesses
-#[derive(Debug, Clone, Default)]
-pub struct HardModeConstraints {
-    /// Letters that must be in specific positions (green)
-    pub required_positions: [Option<char>; WORD_LENGTH],
-    /// Letters that must appear somewhere in the word (yellow)
-    pub required_letters: Vec<char>,
+/// How an automatic solve ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SolveOutcome {
+    /// The last guess was the answer
+    Solved,
+    /// The guess limit was reached first
+    Unsolved,
+    /// The strategy could not narrow the candidates down any further
+    Stuck,
 }
 
-impl HardModeConstraints {
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Update constraints based on a guess and its feedback
-    pub fn update(&mut self, guess: &str, pattern: FeedbackPattern) {
-        let feedbacks = pattern.to_feedbacks();
-        let guess_chars: Vec<char> = guess.chars().collect();
-
-        for (i, &fb) in feedbacks.iter().enumerate() {
-            match fb {
-                Feedback::Correct => {
-                    self.required_positions[i] = Some(guess_chars[i]);
-                }
-                Feedback::Present => {
-                    if !self.required_letters.contains(&guess_chars[i]) {
-                        self.required_letters.push(guess_chars[i]);
-                    }
-                }
-                Feedback::Absent => {}
-            }
+impl SolveOutcome {
+    /// The outcome as written to CSV and JSON
+    pub fn name(self) -> &'static str {
+        match self {
+            SolveOutcome::Solved => "solved",
+            SolveOutcome::Unsolved => "unsolved",
+            SolveOutcome::Stuck => "stuck",
         }
     }
+}
 
-    /// Check if a word satisfies all hard mode constraints
-    pub fn is_valid(&self, word: &str) -> bool {
-        let word_chars: Vec<char> = word.chars().collect();
-
-        for (i, &required) in self.required_positions.iter().enumerate() {
-            if let Some(c) = required {
-                if word_chars.get(i) != Some(&c) {
-                    return false;
-                }
-            }
-        }
+impl Serialize for SolveOutcome {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
 
-        for &required in &self.required_letters {
-            if !word_chars.contains(&required) {
-                return false;
-            }
-        }
+/// The guesses made by an automatic solve and how it ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveResult {
+    /// Every guess with its feedback, in order
+    pub guesses: Vec<(String, FeedbackPattern)>,
+    pub outcome: SolveOutcome,
+}
 
-        true
+impl SolveResult {
+    pub fn is_solved(&self) -> bool {
+        self.outcome == SolveOutcome::Solved
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.required_positions.iter().all(|p| p.is_none()) && self.required_letters.is_empty()
+    /// Number of guesses made
+    pub fn num_guesses(&self) -> usize {
+        self.guesses.len()
     }
 }
 
 /// The main Wordle solver
+///
+/// Words are stored once behind `Arc`s and tracked by index, so cloning a
+/// solver (as the benchmarks do per target) only copies the candidate set.
+/// Clones also share a [`DecisionCache`], so a guess chosen by one is reused
+/// by the others.
 #[derive(Debug, Clone)]
 pub struct WordleSolver {
-    all_words: Vec<String>,
-    possible_answers: Vec<String>,
-    hard_mode: bool,
+    /// Every word the solver is allowed to guess
+    all_words: Arc<[String]>,
+    /// Words that can be the hidden answer at the start of a game
+    answers: Arc<[String]>,
+    /// Position of each answer within `all_words`
+    answer_guess_index: Arc<[usize]>,
+    /// Lookup from a guess word to its index in `all_words`
+    guess_index: Arc<HashMap<String, usize>>,
+    /// `all_words` packed for feedback without the pattern matrix
+    packed_words: Arc<[PackedWord]>,
+    /// Indices into `answers` still consistent with the feedback so far
+    candidates: CandidateSet,
+    /// Lookup from an answer word to its index in `answers`
+    answer_index: Arc<HashMap<String, usize>>,
+    /// Prior weight of each answer, or `None` when all are equally likely
+    answer_weights: Option<Arc<[f64]>>,
+    word_length: usize,
+    matrix: Option<Arc<PatternMatrix>>,
+    hard_mode: HardMode,
+    strategy: Arc<dyn GuessStrategy>,
+    /// Everything learned from feedback so far, tracked in every mode so hard
+    /// mode can be switched on mid-game
     constraints: HardModeConstraints,
+    /// Feedback applied since the last reset, oldest first
+    history: Vec<(String, FeedbackPattern)>,
+    /// Steps taken back by `undo`, most recently undone last
+    undone: Vec<(String, FeedbackPattern)>,
+    /// Guesses allowed by the automatic solves, or `None` for no limit
+    max_guesses: Option<usize>,
+    /// Guesses played first, in order, before the strategy takes over
+    opening: Arc<[String]>,
+    /// The opening is abandoned once this many candidates or fewer remain
+    opening_cutoff: usize,
+    /// Best guesses already chosen, shared with clones
+    decisions: Arc<DecisionCache>,
 }
 
 impl WordleSolver {
-    pub fn new(words: Vec<String>) -> Self {
-        Self {
-            possible_answers: words.clone(),
-            all_words: words,
-            hard_mode: false,
+    /// Create a solver where every word is both a valid guess and a possible answer
+    pub fn new(words: Vec<String>) -> Result<Self, WordleError> {
+        Self::with_answers(words.clone(), words)
+    }
+
+    /// Create a solver with a separate answer pool and allowed-guess list.
+    ///
+    /// Answers missing from `guesses` are appended to it, since the hidden
+    /// word is always a legal guess. The word length is taken from the first
+    /// answer (or guess).
+    ///
+    /// Fails with [`WordleError::WrongLength`] for the first word of another
+    /// length, or if a word is not all lowercase ASCII letters or the length
+    /// is over [`crate::MAX_WORD_LENGTH`].
+    pub fn with_answers(answers: Vec<String>, guesses: Vec<String>) -> Result<Self, WordleError> {
+        let word_length = answers
+            .first()
+            .or(guesses.first())
+            .map_or(WORD_LENGTH, |w| w.len());
+        if let Some(word) = answers.iter().chain(&guesses).find(|w| w.len() != word_length) {
+            return Err(WordleError::WrongLength {
+                word: word.clone(),
+                expected: word_length,
+            });
+        }
+        let mut all_words = guesses;
+        let mut guess_index: HashMap<String, usize> = HashMap::with_capacity(all_words.len());
+        for (i, word) in all_words.iter().enumerate() {
+            guess_index.entry(word.clone()).or_insert(i);
+        }
+
+        let answer_guess_index: Vec<usize> = answers
+            .iter()
+            .map(|word| match guess_index.get(word) {
+                Some(&i) => i,
+                None => {
+                    all_words.push(word.clone());
+                    guess_index.insert(word.clone(), all_words.len() - 1);
+                    all_words.len() - 1
+                }
+            })
+            .collect();
+        let mut answer_index: HashMap<String, usize> = HashMap::with_capacity(answers.len());
+        for (a, word) in answers.iter().enumerate() {
+            answer_index.entry(word.clone()).or_insert(a);
+        }
+        // Every answer is in `all_words` by now, so packing checks them all
+        let packed_words = all_words
+            .iter()
+            .map(|word| PackedWord::new(word))
+            .collect::<Result<Arc<[PackedWord]>, WordleError>>()?;
+
+        Ok(Self {
+            candidates: CandidateSet::full(answers.len()),
+            answer_index: Arc::new(answer_index),
+            answer_weights: None,
+            answers: answers.into(),
+            answer_guess_index: answer_guess_index.into(),
+            guess_index: Arc::new(guess_index),
+            packed_words,
+            all_words: all_words.into(),
+            word_length,
+            matrix: None,
+            hard_mode: HardMode::Off,
+            strategy: Arc::new(EntropyStrategy),
             constraints: HardModeConstraints::new(),
+            history: Vec::new(),
+            undone: Vec::new(),
+            max_guesses: Some(MAX_GUESSES),
+            opening: Arc::new([]),
+            opening_cutoff: 2,
+            decisions: Arc::default(),
+        })
+    }
+
+    /// Precompute feedback for every guess/answer pair.
+    ///
+    /// Afterwards entropy scoring, filtering and the benchmarks use table
+    /// lookups instead of recomputing feedback.
+    pub fn build_pattern_matrix(&mut self) {
+        if self.matrix.is_none() {
+            let matrix = PatternMatrix::build(self.word_length, &self.all_words, &self.answers)
+                .expect("solver words are validated on construction");
+            self.matrix = Some(Arc::new(matrix));
+        }
+    }
+
+    /// Memory-map the pattern matrix cached at `path`, or build it and write
+    /// the cache if the file is missing or was built from different word lists.
+    ///
+    /// Returns where the table came from, including any error writing the cache.
+    pub fn load_or_build_pattern_matrix(&mut self, path: &Path) -> CacheStatus {
+        let (matrix, status) = PatternMatrix::load_or_build(path, self.word_length, &self.all_words, &self.answers)
+            .expect("solver words are validated on construction");
+        self.matrix = Some(Arc::new(matrix));
+        status
+    }
+
+    /// Use an already-built pattern matrix.
+    ///
+    /// Fails with [`WordleError::MatrixMismatch`] unless the matrix was built
+    /// from this solver's `all_words()` and `answers()` in the same order.
+    pub fn set_pattern_matrix(&mut self, matrix: Arc<PatternMatrix>) -> Result<(), WordleError> {
+        if matrix.word_length() != self.word_length || !matrix.matches(&self.all_words, &self.answers) {
+            return Err(WordleError::MatrixMismatch);
+        }
+        self.matrix = Some(matrix);
+        Ok(())
+    }
+
+    pub fn pattern_matrix(&self) -> Option<&Arc<PatternMatrix>> {
+        self.matrix.as_ref()
+    }
+
+    /// Weight answers by their frequency instead of treating them as equally likely
+    pub fn set_priors(&mut self, priors: &WordPriors) {
+        let weights: Vec<f64> = self.answers.iter().map(|word| priors.weight(word)).collect();
+        self.answer_weights = Some(weights.into());
+        self.clear_decision_cache();
+    }
+
+    /// Go back to treating every answer as equally likely
+    pub fn clear_priors(&mut self) {
+        if self.answer_weights.take().is_some() {
+            self.clear_decision_cache();
+        }
+    }
+
+    pub fn has_priors(&self) -> bool {
+        self.answer_weights.is_some()
+    }
+
+    /// Prior weight of answer index `answer` (1 without priors)
+    #[inline]
+    pub fn answer_weight(&self, answer: usize) -> f64 {
+        match &self.answer_weights {
+            Some(weights) => weights[answer],
+            None => 1.0,
+        }
+    }
+
+    /// Probability that answer index `answer` is the hidden word, given the
+    /// feedback so far (0 if it has been ruled out)
+    pub fn answer_probability(&self, answer: usize) -> f64 {
+        if !self.candidates.contains(answer) {
+            return 0.0;
+        }
+        self.answer_weight(answer) / self.candidate_weight()
+    }
+
+    /// Remaining words with their posterior probability, most likely first
+    pub fn posteriors(&self) -> Vec<(&str, f64)> {
+        let total = self.candidate_weight();
+        let mut posteriors: Vec<(&str, f64)> = self
+            .candidates
+            .iter()
+            .map(|a| (self.answers[a].as_str(), self.answer_weight(a) / total))
+            .collect();
+        posteriors.sort_by(|a, b| b.1.total_cmp(&a.1));
+        posteriors
+    }
+
+    /// Total prior weight of the remaining candidates
+    fn candidate_weight(&self) -> f64 {
+        match &self.answer_weights {
+            Some(weights) => self.candidates.iter().map(|a| weights[a]).sum(),
+            None => self.candidates.len() as f64,
         }
     }
 
+    /// Length of every word this solver guesses and answers
+    pub fn word_length(&self) -> usize {
+        self.word_length
+    }
+
+    /// Enable or disable the official (strict) hard mode rules
     pub fn set_hard_mode(&mut self, enabled: bool) {
-        self.hard_mode = enabled;
+        self.set_hard_mode_rules(if enabled { HardMode::Strict } else { HardMode::Off });
     }
 
     pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode.is_enabled()
+    }
+
+    /// Choose which hard mode rules restrict the solver's guesses
+    pub fn set_hard_mode_rules(&mut self, mode: HardMode) {
+        if mode != self.hard_mode {
+            self.hard_mode = mode;
+            self.clear_decision_cache();
+        }
+    }
+
+    pub fn hard_mode_rules(&self) -> HardMode {
         self.hard_mode
     }
 
+    /// Choose how guesses are ranked
+    pub fn set_strategy(&mut self, strategy: Arc<dyn GuessStrategy>) {
+        self.strategy = strategy;
+        self.clear_decision_cache();
+    }
+
+    pub fn strategy(&self) -> &Arc<dyn GuessStrategy> {
+        &self.strategy
+    }
+
+    /// Decisions remembered by [`WordleSolver::find_best_guess`]
+    pub fn decision_cache(&self) -> &DecisionCache {
+        &self.decisions
+    }
+
+    /// Start a new, empty decision cache. Clones made before keep the old one.
+    pub fn clear_decision_cache(&mut self) {
+        self.decisions = Arc::default();
+    }
+
+    /// Limit the guesses made by [`WordleSolver::solve_with_feedback`]
+    /// (six by default), or remove the limit with `None`
+    pub fn set_max_guesses(&mut self, max_guesses: Option<usize>) {
+        self.max_guesses = max_guesses;
+    }
+
+    pub fn max_guesses(&self) -> Option<usize> {
+        self.max_guesses
+    }
+
+    /// Always open with `guesses`, in order, instead of asking the strategy.
+    ///
+    /// Each opening guess is played only while the game has followed the
+    /// opening so far, more than [`WordleSolver::opening_cutoff`] candidates
+    /// remain and hard mode (if on) still allows it; otherwise the strategy
+    /// takes over for the rest of the game. An empty list clears the opening.
+    pub fn set_opening(&mut self, guesses: Vec<String>) -> Result<(), WordleError> {
+        for guess in &guesses {
+            validate_word(guess, self.word_length)?;
+            if !self.guess_index.contains_key(guess) {
+                return Err(WordleError::NotInDictionary(guess.clone()));
+            }
+        }
+        self.opening = guesses.into();
+        Ok(())
+    }
+
+    pub fn opening(&self) -> &[String] {
+        &self.opening
+    }
+
+    /// Stop following the opening once `cutoff` or fewer candidates remain
+    /// (two by default). With a higher cutoff, later opening guesses are only
+    /// played when the earlier ones were not informative.
+    pub fn set_opening_cutoff(&mut self, cutoff: usize) {
+        self.opening_cutoff = cutoff;
+    }
+
+    pub fn opening_cutoff(&self) -> usize {
+        self.opening_cutoff
+    }
+
+    /// Index of the opening guess to play now, if the opening still applies
+    pub(crate) fn opening_guess(&self) -> Option<usize> {
+        let step = self.history.len();
+        let word = self.opening.get(step)?;
+        let followed = self.history.iter().zip(self.opening.iter()).all(|((guess, _), word)| guess == word);
+        if !followed || self.candidates.len() <= self.opening_cutoff {
+            return None;
+        }
+        if self.hard_mode.is_enabled() && !self.constraints.allows(word, self.hard_mode) {
+            return None;
+        }
+        self.guess_index_of(word)
+    }
+
+    /// Constraints accumulated from the feedback applied so far
+    pub fn constraints(&self) -> &HardModeConstraints {
+        &self.constraints
+    }
+
     pub fn remaining_count(&self) -> usize {
-        self.possible_answers.len()
+        self.candidates.len()
     }
 
-    pub fn possible_answers(&self) -> &[String] {
-        &self.possible_answers
+    /// Words still consistent with the feedback so far, listed from
+    /// [`WordleSolver::candidates`]
+    pub fn possible_answers(&self) -> Vec<&str> {
+        self.candidates.words(&self.answers).collect()
     }
 
+    /// All words that may be guessed
     pub fn all_words(&self) -> &[String] {
         &self.all_words
     }
 
+    /// The initial answer pool, before any feedback has been applied
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    /// Index into `answers()` of an answer word, if it is in the answer list
+    pub fn answer_index_of(&self, word: &str) -> Option<usize> {
+        self.answer_index.get(word).copied()
+    }
+
+    /// Whether `word` is still consistent with the feedback so far
+    pub fn is_possible_answer(&self, word: &str) -> bool {
+        self.answer_index_of(word).is_some_and(|a| self.candidates.contains(a))
+    }
+
+    /// The remaining candidates as a set of indices into `answers()`
+    pub fn candidates(&self) -> &CandidateSet {
+        &self.candidates
+    }
+
+    /// Make every answer a candidate again
+    fn reset_candidates(&mut self) {
+        self.candidates = CandidateSet::full(self.answers.len());
+    }
+
     pub fn reset(&mut self) {
-        self.possible_answers = self.all_words.clone();
+        self.reset_candidates();
         self.constraints = HardModeConstraints::new();
+        self.history.clear();
+        self.undone.clear();
     }
 
-    pub fn apply_feedback(&mut self, guess: &str, pattern: FeedbackPattern) {
-        if self.hard_mode {
-            self.constraints.update(guess, pattern);
+    /// Guesses and feedback applied since the last reset, oldest first
+    pub fn history(&self) -> &[(String, FeedbackPattern)] {
+        &self.history
+    }
+
+    /// Take back the most recent feedback, returning it
+    pub fn undo(&mut self) -> Option<(String, FeedbackPattern)> {
+        let steps = self.history.len().checked_sub(1)?;
+        self.rewind_to(steps);
+        self.undone.last().cloned()
+    }
+
+    /// Re-apply the most recently undone feedback, returning it
+    pub fn redo(&mut self) -> Option<(String, FeedbackPattern)> {
+        let (guess, pattern) = self.undone.pop()?;
+        self.apply_step(&guess, pattern)
+            .expect("undone feedback was consistent when first applied");
+        self.history.push((guess.clone(), pattern));
+        Some((guess, pattern))
+    }
+
+    /// Rewind the game to how it was after the first `steps` pieces of
+    /// feedback, restoring the candidates and hard mode constraints.
+    ///
+    /// The later steps can be brought back with [`WordleSolver::redo`]. Asking
+    /// for more steps than have been played leaves the game unchanged.
+    pub fn rewind_to(&mut self, steps: usize) {
+        if steps >= self.history.len() {
+            return;
         }
-        self.possible_answers.retain(|word| {
-            FeedbackPattern::calculate(guess, word) == pattern
-        });
+
+        let mut history = std::mem::take(&mut self.history);
+        self.undone.extend(history.drain(steps..).rev());
+        self.reset_candidates();
+        self.constraints = HardModeConstraints::new();
+        for (guess, pattern) in &history {
+            self.apply_step(guess, *pattern)
+                .expect("recorded feedback was consistent when first applied");
+        }
+        self.history = history;
     }
 
-    fn valid_guesses(&self) -> Vec<&String> {
-        if self.hard_mode && !self.constraints.is_empty() {
-            self.all_words
-                .iter()
-                .filter(|w| self.constraints.is_valid(w))
+    /// Feedback for guess index `guess` against answer index `answer`
+    #[inline]
+    pub fn pattern_for(&self, guess: usize, answer: usize) -> FeedbackPattern {
+        match &self.matrix {
+            Some(matrix) => matrix.get(guess, answer),
+            None => FeedbackPattern::calculate_packed(
+                &self.packed_words[guess],
+                &self.packed_words[self.answer_guess_index[answer]],
+            ),
+        }
+    }
+
+    /// Narrow the candidates down to those giving `pattern` for `guess`, and
+    /// record the step in the history.
+    ///
+    /// Fails without changing anything if the guess or pattern is malformed,
+    /// or if no remaining candidate gives that feedback.
+    pub fn apply_feedback(&mut self, guess: &str, pattern: FeedbackPattern) -> Result<(), WordleError> {
+        self.apply_step(guess, pattern)?;
+        self.history.push((guess.to_string(), pattern));
+        self.undone.clear();
+        Ok(())
+    }
+
+    fn apply_step(&mut self, guess: &str, pattern: FeedbackPattern) -> Result<(), WordleError> {
+        validate_word(guess, self.word_length)?;
+        if pattern.word_len() != self.word_length {
+            return Err(WordleError::PatternLength {
+                pattern: pattern.to_letter_string(),
+                expected: self.word_length,
+            });
+        }
+
+        let remaining = match self.guess_index.get(guess).copied() {
+            Some(g) => self.filtered(|a| self.pattern_for(g, a) == pattern),
+            None => self.filtered(|a| FeedbackPattern::calculate(guess, &self.answers[a]) == pattern),
+        };
+        if remaining.is_empty() {
+            return Err(WordleError::InconsistentFeedback {
+                guess: guess.to_string(),
+                pattern,
+            });
+        }
+
+        self.constraints.update(guess, pattern);
+        self.candidates = remaining;
+        Ok(())
+    }
+
+    /// The remaining candidates for which `keep` holds
+    fn filtered(&self, keep: impl Fn(usize) -> bool) -> CandidateSet {
+        CandidateSet::from_indices(self.answers.len(), self.candidates.iter().filter(|&a| keep(a)))
+    }
+
+    /// Guess indices allowed by the hard mode rules under `constraints`
+    pub(crate) fn valid_guesses_under(&self, constraints: &HardModeConstraints) -> Vec<usize> {
+        if self.hard_mode.is_enabled() && !constraints.is_empty() {
+            (0..self.all_words.len())
+                .filter(|&g| constraints.allows(&self.all_words[g], self.hard_mode))
                 .collect()
         } else {
-            self.all_words.iter().collect()
+            (0..self.all_words.len()).collect()
         }
     }
 
-    pub fn calculate_entropy_for_word(&self, guess: &str) -> f64 {
-        let n = self.possible_answers.len() as f64;
-        if n <= 1.0 {
-            return 0.0;
-        }
+    /// Entropy of any word of the right length over the remaining candidates
+    pub fn calculate_entropy_for_word(&self, guess: &str) -> Result<f64, WordleError> {
+        validate_word(guess, self.word_length)?;
+        Ok(match self.guess_index.get(guess).copied() {
+            Some(g) => self.entropy_among(g, &self.candidate_indices()),
+            None => {
+                let patterns = self
+                    .candidates
+                    .iter()
+                    .map(|a| (FeedbackPattern::calculate(guess, &self.answers[a]), self.answer_weight(a)));
+                weighted_entropy_of(patterns, self.word_length)
+            }
+        })
+    }
 
-        let mut pattern_counts = [0u32; FeedbackPattern::NUM_PATTERNS];
+    /// Indices into `all_words()` of the guesses currently allowed
+    pub fn valid_guess_indices(&self) -> Vec<usize> {
+        self.valid_guesses_under(&self.constraints)
+    }
+
+    /// Entropy of guess index `guess` over an arbitrary set of answer indices,
+    /// weighted by the answer priors if any are set
+    pub fn entropy_among(&self, guess: usize, candidates: &[usize]) -> f64 {
+        if self.answer_weights.is_some() {
+            let patterns = candidates
+                .iter()
+                .map(|&a| (self.pattern_for(guess, a), self.answer_weight(a)));
+            return weighted_entropy_of(patterns, self.word_length);
+        }
+        let patterns = candidates.iter().map(|&a| self.pattern_for(guess, a));
+        entropy_of(patterns, candidates.len(), self.word_length)
+    }
 
-        for answer in &self.possible_answers {
-            let pattern = FeedbackPattern::calculate(guess, answer);
-            pattern_counts[pattern.0 as usize] += 1;
+    /// Number of candidates producing each feedback pattern, indexed by code
+    pub fn pattern_counts(&self, guess: usize, candidates: &[usize]) -> Vec<u32> {
+        let mut counts = vec![0u32; FeedbackPattern::num_patterns(self.word_length)];
+        for &a in candidates {
+            counts[self.pattern_for(guess, a).index()] += 1;
         }
+        counts
+    }
 
-        let mut entropy = 0.0;
-        for &count in &pattern_counts {
-            if count > 0 {
-                let p = count as f64 / n;
-                entropy -= p * p.log2();
+    /// Largest bucket size and number of non-empty buckets for a guess
+    pub fn bucket_stats(&self, guess: usize, candidates: &[usize]) -> (usize, usize) {
+        let counts = self.pattern_counts(guess, candidates);
+        let largest = counts.iter().copied().max().unwrap_or(0) as usize;
+        let buckets = counts.iter().filter(|&&c| c > 0).count();
+        (largest, buckets)
+    }
+
+    /// Group answer indices by the feedback guess `guess` would produce
+    pub fn partition(&self, guess: usize, candidates: &[usize]) -> Vec<(FeedbackPattern, Vec<usize>)> {
+        let mut keyed: Vec<(u16, usize)> = candidates
+            .iter()
+            .map(|&a| (self.pattern_for(guess, a).code(), a))
+            .collect();
+        keyed.sort_unstable();
+
+        let mut buckets: Vec<(FeedbackPattern, Vec<usize>)> = Vec::new();
+        for (code, a) in keyed {
+            match buckets.last_mut() {
+                Some((pattern, bucket)) if pattern.code() == code => bucket.push(a),
+                _ => buckets.push((FeedbackPattern::from_code(code, self.word_length), vec![a])),
             }
         }
+        buckets
+    }
+
+    /// Mark which guess indices are still possible answers among `candidates`
+    pub fn candidate_mask(&self, candidates: &[usize]) -> Vec<bool> {
+        let mut is_candidate = vec![false; self.all_words.len()];
+        for &a in candidates {
+            is_candidate[self.answer_guess_index[a]] = true;
+        }
+        is_candidate
+    }
+
+    /// Score every valid guess by entropy over `candidates`, best first
+    pub(crate) fn ranked_by_entropy(
+        &self,
+        candidates: &[usize],
+        constraints: &HardModeConstraints,
+    ) -> Vec<(usize, f64)> {
+        let is_candidate = self.candidate_mask(candidates);
+        let mut scored: Vec<(usize, f64)> = self
+            .valid_guesses_under(constraints)
+            .par_iter()
+            .map(|&g| (g, self.entropy_among(g, candidates)))
+            .collect();
+
+        scored.sort_by(|&(ga, ea), &(gb, eb)| {
+            match eb.partial_cmp(&ea) {
+                Some(std::cmp::Ordering::Equal) => is_candidate[gb].cmp(&is_candidate[ga]),
+                Some(ord) => ord,
+                None => std::cmp::Ordering::Equal,
+            }
+        });
+        scored
+    }
 
-        entropy
+    /// Indices into `answers()` of the words still consistent with the
+    /// feedback, in increasing order, listed from [`WordleSolver::candidates`]
+    pub fn candidate_indices(&self) -> Vec<usize> {
+        self.candidates.iter().collect()
     }
 
+    /// The guess word at index `guess` of `all_words()`
+    pub fn word(&self, guess: usize) -> &str {
+        &self.all_words[guess]
+    }
+
+    /// Index into `all_words()` of a guess word, if it is in the guess list
+    pub fn guess_index_of(&self, word: &str) -> Option<usize> {
+        self.guess_index.get(word).copied()
+    }
+
+    /// Index into `all_words()` of answer index `answer`
+    pub fn guess_index_of_answer(&self, answer: usize) -> usize {
+        self.answer_guess_index[answer]
+    }
+
+    /// Build the analysis entry for guess index `guess` with the given entropy
+    pub fn analysis(&self, guess: usize, entropy: f64, is_candidate: bool) -> GuessAnalysis {
+        let (worst_case_remaining, bucket_count) = self.bucket_stats(guess, &self.candidate_indices());
+        let answer_probability = if is_candidate {
+            self.answer_index_of(&self.all_words[guess])
+                .map_or(0.0, |a| self.answer_probability(a))
+        } else {
+            0.0
+        };
+        GuessAnalysis {
+            word: self.all_words[guess].clone(),
+            entropy,
+            expected_remaining: self.candidates.len() as f64 / 2_f64.powf(entropy),
+            is_possible_answer: is_candidate,
+            expected_guesses: None,
+            worst_case_remaining,
+            bucket_count,
+            answer_probability,
+        }
+    }
+
+    /// The strategy's best guess for the remaining candidates.
+    ///
+    /// The choice is remembered in the [`DecisionCache`] shared with this
+    /// solver's clones, keyed by the remaining candidates (and, in hard mode,
+    /// the constraints), so each state is only ranked once.
     pub fn find_best_guess(&self) -> Option<GuessAnalysis> {
-        self.find_best_guesses(1).into_iter().next()
+        if self.candidates.len() <= 2 {
+            return self.find_best_guesses(1).into_iter().next();
+        }
+        let key = DecisionKey::new(self, self.candidates.clone(), &self.constraints);
+        if let Some(decision) = self.decisions.get(&key) {
+            return Some(decision);
+        }
+        let best = self.find_best_guesses(1).into_iter().next()?;
+        self.decisions.insert(key, best.clone());
+        Some(best)
+    }
+
+    /// The guess to play next: the opening's while it applies (see
+    /// [`WordleSolver::set_opening`]), otherwise the strategy's best
+    pub fn next_guess(&self) -> Option<GuessAnalysis> {
+        match self.opening_guess() {
+            Some(g) => {
+                let is_candidate = self.is_possible_answer(self.word(g));
+                Some(self.analysis(g, self.entropy_among(g, &self.candidate_indices()), is_candidate))
+            }
+            None => self.find_best_guess(),
+        }
     }
 
     pub fn find_best_guesses(&self, n: usize) -> Vec<GuessAnalysis> {
-        if self.possible_answers.is_empty() {
+        let candidates = self.candidate_indices();
+        if candidates.is_empty() {
             return vec![];
         }
 
-        if self.possible_answers.len() == 1 {
+        if candidates.len() == 1 {
             return vec![GuessAnalysis {
-                word: self.possible_answers[0].clone(),
+                word: self.answers[candidates[0]].clone(),
                 entropy: 0.0,
                 expected_remaining: 1.0,
                 is_possible_answer: true,
+                expected_guesses: Some(1.0),
+                worst_case_remaining: 1,
+                bucket_count: 1,
+                answer_probability: 1.0,
             }];
         }
 
-        if self.possible_answers.len() == 2 {
+        if candidates.len() == 2 {
+            // Guess the more likely word; either way the other one is left
+            let (first, second) = (candidates[0], candidates[1]);
+            let best = if self.answer_weight(second) > self.answer_weight(first) { second } else { first };
+            let p = self.answer_probability(best);
+            // A prior that dwarfs the other rounds `p` to 1, so treat 0·log 0 as 0
+            let term = |q: f64| if q > 0.0 { q * q.log2() } else { 0.0 };
+            let entropy = -(term(p) + term(1.0 - p));
             return vec![GuessAnalysis {
-                word: self.possible_answers[0].clone(),
-                entropy: 1.0,
-                expected_remaining: 1.0,
+                word: self.answers[best].clone(),
+                entropy,
+                expected_remaining: 2.0 / 2_f64.powf(entropy),
                 is_possible_answer: true,
+                expected_guesses: Some(2.0 - p),
+                worst_case_remaining: 1,
+                bucket_count: 2,
+                answer_probability: p,
             }];
         }
 
-        let valid_guesses = self.valid_guesses();
-        let mut analyses: Vec<GuessAnalysis> = valid_guesses
-            .par_iter()
-            .map(|word| {
-                let entropy = self.calculate_entropy_for_word(word);
-                let is_possible = self.possible_answers.contains(*word);
-                let expected_remaining =
-                    self.possible_answers.len() as f64 / 2_f64.powf(entropy);
-
-                GuessAnalysis {
-                    word: (*word).clone(),
-                    entropy,
-                    expected_remaining,
-                    is_possible_answer: is_possible,
-                }
-            })
-            .collect();
-
-        analyses.sort_by(|a, b| {
-            match b.entropy.partial_cmp(&a.entropy) {
-                Some(std::cmp::Ordering::Equal) => b.is_possible_answer.cmp(&a.is_possible_answer),
-                Some(ord) => ord,
-                None => std::cmp::Ordering::Equal,
-            }
-        });
-
-        analyses.truncate(n);
-        analyses
+        self.strategy.rank(self, n)
     }
 
     pub fn get_top_guesses(&self, n: usize) -> Vec<GuessAnalysis> {
@@ -228,63 +781,106 @@ impl WordleSolver {
     }
 
     /// Solve a Wordle puzzle automatically, given a function that provides feedback
-    /// Returns the sequence of guesses made
-    pub fn solve_with_feedback<F>(&mut self, mut get_feedback: F) -> Vec<(String, FeedbackPattern)>
+    /// Returns the sequence of guesses made and how the game ended
+    ///
+    /// Guesses come from [`WordleSolver::next_guess`], so any opening is
+    /// played first.
+    ///
+    /// Stops after [`WordleSolver::max_guesses`] guesses, or as soon as a
+    /// guess fails to rule out any candidate, since the strategy would only
+    /// repeat it.
+    ///
+    /// Fails if `get_feedback` returns a pattern of the wrong length, or one no
+    /// remaining candidate could give.
+    pub fn solve_with_feedback<F>(&mut self, mut get_feedback: F) -> Result<SolveResult, WordleError>
     where
         F: FnMut(&str) -> FeedbackPattern,
     {
         let mut guesses = Vec::new();
 
-        for _ in 0..6 {
-            let best = match self.find_best_guess() {
+        loop {
+            if self.max_guesses.is_some_and(|max| guesses.len() >= max) {
+                return Ok(SolveResult { guesses, outcome: SolveOutcome::Unsolved });
+            }
+            let best = match self.next_guess() {
                 Some(g) => g,
-                None => break,
+                None => return Ok(SolveResult { guesses, outcome: SolveOutcome::Stuck }),
             };
 
             let pattern = get_feedback(&best.word);
             guesses.push((best.word.clone(), pattern));
 
             if pattern.is_win() {
-                break;
+                return Ok(SolveResult { guesses, outcome: SolveOutcome::Solved });
             }
 
-            self.apply_feedback(&best.word, pattern);
+            // An opening guess may be uninformative; only the strategy can get stuck
+            let forced = self.opening_guess().is_some();
+            let before = self.remaining_count();
+            self.apply_feedback(&best.word, pattern)?;
+            if !forced && self.remaining_count() == before {
+                return Ok(SolveResult { guesses, outcome: SolveOutcome::Stuck });
+            }
         }
+    }
 
-        guesses
+    /// Solve a puzzle knowing the target word (for testing/benchmarking).
+    ///
+    /// The target must be one of the solver's `answers()`.
+    pub fn solve_for_target(&mut self, target: &str) -> Result<SolveResult, WordleError> {
+        validate_word(target, self.word_length)?;
+        match self.answer_index_of(target) {
+            Some(a) => Ok(self.solve_for_answer_index(a)),
+            None => Err(WordleError::NotInDictionary(target.to_string())),
+        }
     }
 
-    /// Solve a puzzle knowing the target word (for testing/benchmarking)
-    pub fn solve_for_target(&mut self, target: &str) -> Vec<(String, FeedbackPattern)> {
-        self.solve_with_feedback(|guess| FeedbackPattern::calculate(guess, target))
+    pub(crate) fn solve_for_answer_index(&mut self, answer: usize) -> SolveResult {
+        let result = match self.matrix.clone() {
+            Some(matrix) => {
+                let guess_index = self.guess_index.clone();
+                self.solve_with_feedback(|guess| matrix.get(guess_index[guess], answer))
+            }
+            None => {
+                let answers = self.answers.clone();
+                self.solve_with_feedback(|guess| FeedbackPattern::calculate(guess, &answers[answer]))
+            }
+        };
+        // Feedback from a real answer always leaves that answer as a candidate
+        result.expect("feedback from an answer is consistent")
     }
 
-    /// Calculate the average number of guesses needed to solve all words
-    pub fn benchmark_average_guesses(&self) -> f64 {
-        let total_guesses: usize = self
-            .all_words
-            .par_iter()
-            .map(|target| {
-                let mut solver = self.clone();
-                let guesses = solver.solve_for_target(target);
-                guesses.len()
-            })
-            .sum();
+    /// Play one game per answer, each from a clone of this solver, returning
+    /// the results in answer order
+    pub(crate) fn solve_every_answer(&self) -> Vec<SolveResult> {
+        // Choose the first guess once, before the games share it
+        self.next_guess();
+        (0..self.answers.len())
+            .into_par_iter()
+            .map(|a| self.clone().solve_for_answer_index(a))
+            .collect()
+    }
 
-        total_guesses as f64 / self.all_words.len() as f64
+    /// Play one game per answer with no guess limit, returning guess counts
+    fn benchmark_guess_counts(&self) -> Vec<usize> {
+        let mut solver = self.clone();
+        solver.max_guesses = None;
+        solver
+            .solve_every_answer()
+            .iter()
+            .map(SolveResult::num_guesses)
+            .collect()
+    }
+
+    /// Calculate the average number of guesses needed to solve every answer
+    pub fn benchmark_average_guesses(&self) -> f64 {
+        let total_guesses: usize = self.benchmark_guess_counts().into_iter().sum();
+        total_guesses as f64 / self.answers.len() as f64
     }
 
-    /// Get distribution of guess counts across all words
+    /// Get distribution of guess counts across all answers
     pub fn benchmark_guess_distribution(&self) -> Vec<(usize, usize)> {
-        let guess_counts: Vec<usize> = self
-            .all_words
-            .par_iter()
-            .map(|target| {
-                let mut solver = self.clone();
-                let guesses = solver.solve_for_target(target);
-                guesses.len()
-            })
-            .collect();
+        let guess_counts = self.benchmark_guess_counts();
 
         let max_guesses = *guess_counts.iter().max().unwrap_or(&0);
         let mut distribution = vec![0usize; max_guesses + 1];
@@ -300,3 +896,46 @@ impl WordleSolver {
             .collect()
     }
 }
+
+/// Shannon entropy (in bits) of the pattern distribution over weighted answers
+fn weighted_entropy_of(patterns: impl Iterator<Item = (FeedbackPattern, f64)>, word_length: usize) -> f64 {
+    let mut pattern_weights = vec![0.0f64; FeedbackPattern::num_patterns(word_length)];
+    let mut total = 0.0;
+    for (pattern, weight) in patterns {
+        pattern_weights[pattern.index()] += weight;
+        total += weight;
+    }
+
+    let mut entropy = 0.0;
+    for &weight in &pattern_weights {
+        if weight > 0.0 {
+            let p = weight / total;
+            entropy -= p * p.log2();
+        }
+    }
+
+    entropy
+}
+
+/// Shannon entropy (in bits) of the pattern distribution over `n` answers
+fn entropy_of(patterns: impl Iterator<Item = FeedbackPattern>, n: usize, word_length: usize) -> f64 {
+    if n <= 1 {
+        return 0.0;
+    }
+
+    let mut pattern_counts = vec![0u32; FeedbackPattern::num_patterns(word_length)];
+    for pattern in patterns {
+        pattern_counts[pattern.index()] += 1;
+    }
+
+    let n = n as f64;
+    let mut entropy = 0.0;
+    for &count in &pattern_counts {
+        if count > 0 {
+            let p = count as f64 / n;
+            entropy -= p * p.log2();
+        }
+    }
+
+    entropy
+}
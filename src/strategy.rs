@@ -0,0 +1,248 @@
+//! Pluggable guess-ranking strategies.
+//!
+//! The solver delegates "which word should I guess next?" to a
+//! [`GuessStrategy`]. Most strategies score each valid guess independently
+//! from the feedback buckets it would produce and share [`rank_by_score`];
+//! others (lookahead, the random baseline) rank guesses their own way.
+
+use crate::lookahead::LookaheadStrategy;
+use crate::solver::{GuessAnalysis, WordleSolver};
+use rayon::prelude::*;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// A rule for choosing the next guess
+pub trait GuessStrategy: Debug + Send + Sync {
+    /// Short name used on the command line and in reports
+    fn name(&self) -> &'static str;
+
+    /// Rank the solver's valid guesses for its current candidates, best first,
+    /// returning at most `n`.
+    ///
+    /// Only called with three or more candidates remaining; the solver handles
+    /// the trivial cases itself.
+    fn rank(&self, solver: &WordleSolver, n: usize) -> Vec<GuessAnalysis>;
+}
+
+/// Names accepted by [`strategy_from_name`]
+pub const STRATEGY_NAMES: &[&str] = &[
+    "entropy",
+    "minimax",
+    "expected-remaining",
+    "letter-frequency",
+    "random",
+    "lookahead",
+];
+
+/// Look up a strategy with default settings by name
+pub fn strategy_from_name(name: &str) -> Option<Arc<dyn GuessStrategy>> {
+    let strategy: Arc<dyn GuessStrategy> = match name.to_ascii_lowercase().as_str() {
+        "entropy" => Arc::new(EntropyStrategy),
+        "minimax" | "worst-case" => Arc::new(MinimaxStrategy),
+        "expected-remaining" | "remaining" => Arc::new(ExpectedRemainingStrategy),
+        "letter-frequency" | "letters" | "frequency" => Arc::new(LetterFrequencyStrategy),
+        "random" | "random-candidate" => Arc::new(RandomCandidateStrategy::default()),
+        "lookahead" | "expected" => Arc::new(LookaheadStrategy::default()),
+        _ => return None,
+    };
+    Some(strategy)
+}
+
+/// Rank every valid guess by `score` (higher is better).
+///
+/// Ties prefer guesses that could be the answer, then higher entropy.
+pub fn rank_by_score<F>(solver: &WordleSolver, n: usize, score: F) -> Vec<GuessAnalysis>
+where
+    F: Fn(usize) -> f64 + Sync,
+{
+    let candidates = &solver.candidate_indices();
+    let is_candidate = solver.candidate_mask(candidates);
+
+    let mut scored: Vec<(usize, f64, f64)> = solver
+        .valid_guess_indices()
+        .into_par_iter()
+        .map(|g| (g, score(g), solver.entropy_among(g, candidates)))
+        .collect();
+
+    scored.sort_by(|a, b| {
+        b.1.total_cmp(&a.1)
+            .then(is_candidate[b.0].cmp(&is_candidate[a.0]))
+            .then(b.2.total_cmp(&a.2))
+    });
+
+    scored
+        .into_iter()
+        .take(n)
+        .map(|(g, _, entropy)| solver.analysis(g, entropy, is_candidate[g]))
+        .collect()
+}
+
+/// Maximize the expected information (Shannon entropy) of the feedback
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EntropyStrategy;
+
+impl GuessStrategy for EntropyStrategy {
+    fn name(&self) -> &'static str {
+        "entropy"
+    }
+
+    fn rank(&self, solver: &WordleSolver, n: usize) -> Vec<GuessAnalysis> {
+        let candidates = &solver.candidate_indices();
+        let is_candidate = solver.candidate_mask(candidates);
+        solver
+            .ranked_by_entropy(candidates, solver.constraints())
+            .into_iter()
+            .take(n)
+            .map(|(g, entropy)| solver.analysis(g, entropy, is_candidate[g]))
+            .collect()
+    }
+}
+
+/// Minimize the size of the largest feedback bucket (the worst case).
+///
+/// Ties are broken by higher entropy, then by preferring possible answers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinimaxStrategy;
+
+impl GuessStrategy for MinimaxStrategy {
+    fn name(&self) -> &'static str {
+        "minimax"
+    }
+
+    fn rank(&self, solver: &WordleSolver, n: usize) -> Vec<GuessAnalysis> {
+        let candidates = &solver.candidate_indices();
+        let is_candidate = solver.candidate_mask(candidates);
+
+        let mut scored: Vec<(usize, usize, f64)> = solver
+            .valid_guess_indices()
+            .into_par_iter()
+            .map(|g| {
+                let (worst_case, _) = solver.bucket_stats(g, candidates);
+                (g, worst_case, solver.entropy_among(g, candidates))
+            })
+            .collect();
+
+        scored.sort_by(|a, b| {
+            a.1.cmp(&b.1)
+                .then(b.2.total_cmp(&a.2))
+                .then(is_candidate[b.0].cmp(&is_candidate[a.0]))
+        });
+
+        scored
+            .into_iter()
+            .take(n)
+            .map(|(g, _, entropy)| solver.analysis(g, entropy, is_candidate[g]))
+            .collect()
+    }
+}
+
+/// Minimize the exact expected number of candidates left after the guess
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExpectedRemainingStrategy;
+
+impl GuessStrategy for ExpectedRemainingStrategy {
+    fn name(&self) -> &'static str {
+        "expected-remaining"
+    }
+
+    fn rank(&self, solver: &WordleSolver, n: usize) -> Vec<GuessAnalysis> {
+        let candidates = &solver.candidate_indices();
+        let total = candidates.len() as f64;
+        rank_by_score(solver, n, |g| {
+            let sum_of_squares: u64 = solver
+                .pattern_counts(g, candidates)
+                .iter()
+                .map(|&count| count as u64 * count as u64)
+                .sum();
+            -(sum_of_squares as f64 / total)
+        })
+    }
+}
+
+/// Prefer guesses whose distinct letters appear in the most candidates.
+///
+/// A cheap human-style heuristic that ignores positions and duplicates.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LetterFrequencyStrategy;
+
+impl GuessStrategy for LetterFrequencyStrategy {
+    fn name(&self) -> &'static str {
+        "letter-frequency"
+    }
+
+    fn rank(&self, solver: &WordleSolver, n: usize) -> Vec<GuessAnalysis> {
+        let mut frequency = [0usize; 26];
+        for a in solver.candidates().iter() {
+            for letter in distinct_letters(&solver.answers()[a]) {
+                frequency[letter] += 1;
+            }
+        }
+
+        rank_by_score(solver, n, |g| {
+            distinct_letters(solver.word(g))
+                .map(|letter| frequency[letter])
+                .sum::<usize>() as f64
+        })
+    }
+}
+
+fn distinct_letters(word: &str) -> impl Iterator<Item = usize> {
+    let mut seen = 0u32;
+    for b in word.bytes() {
+        seen |= 1 << (b - b'a');
+    }
+    (0..26).filter(move |&letter| seen & (1 << letter) != 0)
+}
+
+/// Baseline that guesses a uniformly random remaining candidate.
+///
+/// The choice is derived from `seed` and the current candidates, so a given
+/// game always plays out the same way.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomCandidateStrategy {
+    pub seed: u64,
+}
+
+impl RandomCandidateStrategy {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl GuessStrategy for RandomCandidateStrategy {
+    fn name(&self) -> &'static str {
+        "random"
+    }
+
+    fn rank(&self, solver: &WordleSolver, n: usize) -> Vec<GuessAnalysis> {
+        let candidates = &solver.candidate_indices();
+        let mut state = candidates
+            .iter()
+            .fold(self.seed, |acc, &a| splitmix64(acc ^ a as u64));
+
+        // Partial Fisher-Yates: only the first `n` picks are needed
+        let mut order: Vec<usize> = candidates.to_vec();
+        let picks = n.min(order.len());
+        for i in 0..picks {
+            state = splitmix64(state);
+            let j = i + (state % (order.len() - i) as u64) as usize;
+            order.swap(i, j);
+        }
+
+        order
+            .into_iter()
+            .take(picks)
+            .map(|a| {
+                let g = solver.guess_index_of_answer(a);
+                solver.analysis(g, solver.entropy_among(g, candidates), true)
+            })
+            .collect()
+    }
+}
+
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,427 @@
+//! Complete decision trees and a solver that replays them.
+//!
+//! Once a strategy is fixed, everything it does is captured by a tree: the
+//! opening guess, then the next guess for each feedback pattern, recursively.
+//! Trees can be generated from any [`WordleSolver`], saved as text or JSON and
+//! loaded back into a [`TreeSolver`], which plays without computing anything.
+//!
+//! The text format has one line per answer listing each guess with the
+//! feedback it received, with the number of guesses appended to the final
+//! pattern:
+//!
+//! ```text
+//! tares bbbbb doily bbbbb humpf ggggg3
+//! ```
+
+use crate::error::{validate_word, WordleError};
+use crate::feedback::FeedbackPattern;
+use crate::solver::{SolveOutcome, SolveResult, WordleSolver};
+use crate::MAX_WORD_LENGTH;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// The guess to play at one point of a game and how to continue afterwards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionTree {
+    pub guess: String,
+    /// Whether the guess is itself one of the remaining answers
+    pub is_answer: bool,
+    /// Subtree for each feedback pattern other than a win, sorted by pattern
+    pub children: Vec<(FeedbackPattern, DecisionTree)>,
+}
+
+impl DecisionTree {
+    /// Build the tree played by the solver from its current state: its opening,
+    /// if any (see [`WordleSolver::set_opening`]), then its own strategy.
+    ///
+    /// Returns `None` if there are no candidates left, or if the strategy picks
+    /// a guess that cannot narrow the candidates down.
+    pub fn from_strategy(solver: &WordleSolver) -> Option<DecisionTree> {
+        let best = solver.next_guess()?;
+        let guess = solver.guess_index_of(&best.word)?;
+        let candidates = &solver.candidate_indices();
+
+        let buckets: Vec<_> = solver
+            .partition(guess, candidates)
+            .into_iter()
+            .filter(|(pattern, _)| !pattern.is_win())
+            .collect();
+        let forced = solver.opening_guess().is_some();
+        if !forced && buckets.iter().any(|(_, bucket)| bucket.len() == candidates.len()) {
+            return None;
+        }
+
+        let children = buckets
+            .into_par_iter()
+            .map(|(pattern, _)| {
+                let mut next = solver.clone();
+                next.apply_feedback(&best.word, pattern).ok()?;
+                DecisionTree::from_strategy(&next).map(|child| (pattern, child))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(DecisionTree {
+            guess: best.word,
+            is_answer: best.is_possible_answer,
+            children,
+        })
+    }
+
+    /// Number of answers this tree finds
+    pub fn num_answers(&self) -> usize {
+        self.is_answer as usize + self.children.iter().map(|(_, child)| child.num_answers()).sum::<usize>()
+    }
+
+    /// Sum over every answer of the guesses needed to find it
+    pub fn total_guesses(&self) -> usize {
+        self.num_answers() + self.children.iter().map(|(_, child)| child.total_guesses()).sum::<usize>()
+    }
+
+    pub fn average_guesses(&self) -> f64 {
+        self.total_guesses() as f64 / self.num_answers() as f64
+    }
+
+    /// Most guesses needed for any answer
+    pub fn max_depth(&self) -> usize {
+        1 + self.children.iter().map(|(_, child)| child.max_depth()).max().unwrap_or(0)
+    }
+
+    /// The subtree to continue with after `pattern`
+    pub fn next(&self, pattern: FeedbackPattern) -> Option<&DecisionTree> {
+        self.children
+            .iter()
+            .find(|(p, _)| *p == pattern)
+            .map(|(_, child)| child)
+    }
+
+    /// Every answer with the guesses and feedback leading to it, in tree order
+    pub fn paths(&self) -> Vec<Vec<(String, FeedbackPattern)>> {
+        let mut paths = Vec::new();
+        self.collect_paths(&mut Vec::new(), &mut paths);
+        paths
+    }
+
+    fn collect_paths(
+        &self,
+        prefix: &mut Vec<(String, FeedbackPattern)>,
+        paths: &mut Vec<Vec<(String, FeedbackPattern)>>,
+    ) {
+        if self.is_answer {
+            let mut path = prefix.clone();
+            path.push((self.guess.clone(), FeedbackPattern::all_correct(self.guess.len())));
+            paths.push(path);
+        }
+        for (pattern, child) in &self.children {
+            prefix.push((self.guess.clone(), *pattern));
+            child.collect_paths(prefix, paths);
+            prefix.pop();
+        }
+    }
+
+    /// Format as one line per answer (see the module documentation)
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for path in self.paths() {
+            let steps: Vec<String> = path
+                .iter()
+                .map(|(guess, pattern)| format!("{} {}", guess, pattern.to_letter_string()))
+                .collect();
+            text.push_str(&steps.join(" "));
+            text.push_str(&path.len().to_string());
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Parse the one-line-per-answer text format. Blank lines and lines
+    /// starting with `#` are ignored, and the guess counts are optional.
+    pub fn from_text(text: &str) -> io::Result<DecisionTree> {
+        let mut root: Option<DecisionTree> = None;
+        let mut word_length = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.len() % 2 != 0 {
+                return Err(invalid(line_number, "expected pairs of guess and feedback"));
+            }
+
+            let mut path = Vec::with_capacity(tokens.len() / 2);
+            for (step, pair) in tokens.chunks(2).enumerate() {
+                let guess = parse_guess(pair[0], &mut word_length)
+                    .map_err(|message| invalid(line_number, &message))?;
+                let len = guess.len();
+
+                if !pair[1].is_ascii() {
+                    return Err(invalid(line_number, &format!("invalid feedback '{}'", pair[1])));
+                }
+                // The final pattern may carry the guess count, e.g. `ggggg3`
+                let (letters, count) = pair[1].split_at(pair[1].len().min(len));
+                if !count.is_empty() && count.parse() != Ok(tokens.len() / 2) {
+                    return Err(invalid(line_number, &format!("bad guess count in '{}'", pair[1])));
+                }
+                let pattern = FeedbackPattern::parse_with_length(letters, len)
+                    .map_err(|e| invalid(line_number, &e.to_string()))?;
+
+                let is_last = step == tokens.len() / 2 - 1;
+                if pattern.is_win() != is_last {
+                    return Err(invalid(line_number, "only the final guess may be all green"));
+                }
+                path.push((guess, pattern));
+            }
+
+            let node = root.get_or_insert_with(|| DecisionTree::leaf(&path[0].0));
+            node.insert(&path)
+                .map_err(|message| invalid(line_number, &message))?;
+        }
+
+        root.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "tree has no answers"))
+    }
+
+    /// Format as nested JSON objects of the form
+    /// `{"guess": "tares", "answer": true, "next": {"bbbbb": {...}}}`
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&JsonNode::from(self)).expect("tree serializes to JSON")
+    }
+
+    /// Parse the JSON format written by [`DecisionTree::to_json`]
+    pub fn from_json(json: &str) -> io::Result<DecisionTree> {
+        let node: JsonNode = serde_json::from_str(json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let mut word_length = None;
+        node.into_tree(&mut word_length)
+            .map_err(|message| io::Error::new(io::ErrorKind::InvalidData, message))
+    }
+
+    /// Parse either format, telling them apart by the leading `{` of JSON
+    pub fn parse(s: &str) -> io::Result<DecisionTree> {
+        if s.trim_start().starts_with('{') {
+            Self::from_json(s)
+        } else {
+            Self::from_text(s)
+        }
+    }
+
+    /// Read a tree saved in either format
+    pub fn load(path: &Path) -> io::Result<DecisionTree> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Save the tree, as JSON if `path` ends in `.json` and as text otherwise
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let is_json = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        let contents = if is_json { self.to_json() } else { self.to_text() };
+        fs::write(path, contents)
+    }
+
+    fn leaf(guess: &str) -> DecisionTree {
+        DecisionTree {
+            guess: guess.to_string(),
+            is_answer: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Add one answer's path, starting at this node
+    fn insert(&mut self, path: &[(String, FeedbackPattern)]) -> Result<(), String> {
+        let (guess, pattern) = &path[0];
+        if *guess != self.guess {
+            return Err(format!("expected guess '{}', found '{}'", self.guess, guess));
+        }
+        if pattern.is_win() {
+            if self.is_answer {
+                return Err(format!("answer '{}' listed twice", guess));
+            }
+            self.is_answer = true;
+            return Ok(());
+        }
+        if pattern.word_len() != self.guess.len() {
+            return Err(format!("feedback length does not match '{}'", guess));
+        }
+
+        let position = match self.children.binary_search_by_key(&pattern.code(), |(p, _)| p.code()) {
+            Ok(position) => position,
+            Err(position) => {
+                self.children.insert(position, (*pattern, DecisionTree::leaf(&path[1].0)));
+                position
+            }
+        };
+        self.children[position].1.insert(&path[1..])
+    }
+}
+
+fn invalid(line_number: usize, message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", line_number, message))
+}
+
+/// Validate a guess word, requiring every word in a tree to have the same length
+fn parse_guess(word: &str, word_length: &mut Option<usize>) -> Result<String, String> {
+    let word = word.to_lowercase();
+    if word.is_empty() || word.len() > MAX_WORD_LENGTH || !word.bytes().all(|b| b.is_ascii_lowercase()) {
+        return Err(format!("invalid guess '{}'", word));
+    }
+    match *word_length {
+        Some(len) if len != word.len() => Err(format!("'{}' is not {} letters", word, len)),
+        _ => {
+            *word_length = Some(word.len());
+            Ok(word)
+        }
+    }
+}
+
+/// On-disk shape of a tree node in the JSON format
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonNode {
+    guess: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    answer: bool,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    next: BTreeMap<String, JsonNode>,
+}
+
+impl From<&DecisionTree> for JsonNode {
+    fn from(tree: &DecisionTree) -> Self {
+        JsonNode {
+            guess: tree.guess.clone(),
+            answer: tree.is_answer,
+            next: tree
+                .children
+                .iter()
+                .map(|(pattern, child)| (pattern.to_letter_string(), JsonNode::from(child)))
+                .collect(),
+        }
+    }
+}
+
+impl JsonNode {
+    fn into_tree(self, word_length: &mut Option<usize>) -> Result<DecisionTree, String> {
+        let guess = parse_guess(&self.guess, word_length)?;
+        if !self.answer && self.next.is_empty() {
+            return Err(format!("'{}' leads to no answers", guess));
+        }
+
+        let mut children = Vec::with_capacity(self.next.len());
+        for (letters, child) in self.next {
+            let pattern = FeedbackPattern::parse_with_length(&letters, guess.len())
+                .ok()
+                .filter(|pattern| !pattern.is_win())
+                .ok_or_else(|| format!("invalid feedback '{}' after '{}'", letters, guess))?;
+            children.push((pattern, child.into_tree(word_length)?));
+        }
+        children.sort_by_key(|(pattern, _)| pattern.code());
+
+        Ok(DecisionTree {
+            guess,
+            is_answer: self.answer,
+            children,
+        })
+    }
+}
+
+/// Plays a game by following a [`DecisionTree`], with no search at all
+#[derive(Debug, Clone)]
+pub struct TreeSolver {
+    tree: Arc<DecisionTree>,
+    /// Index of the child taken at each step so far
+    path: Vec<usize>,
+}
+
+impl TreeSolver {
+    pub fn new(tree: DecisionTree) -> Self {
+        Self {
+            tree: Arc::new(tree),
+            path: Vec::new(),
+        }
+    }
+
+    /// Load a tree saved in either format
+    pub fn open(path: &Path) -> io::Result<Self> {
+        DecisionTree::load(path).map(Self::new)
+    }
+
+    pub fn tree(&self) -> &DecisionTree {
+        &self.tree
+    }
+
+    /// The subtree for the current point of the game
+    pub fn current(&self) -> &DecisionTree {
+        self.path
+            .iter()
+            .fold(&*self.tree, |node, &child| &node.children[child].1)
+    }
+
+    /// The guess the tree plays next
+    pub fn next_guess(&self) -> &str {
+        &self.current().guess
+    }
+
+    /// Move on after the current guess received `pattern`. A win ends the
+    /// game and leaves it where it is.
+    ///
+    /// Fails without changing anything if the pattern has the wrong length or
+    /// no answer in the tree gives it.
+    pub fn apply_feedback(&mut self, pattern: FeedbackPattern) -> Result<(), WordleError> {
+        let current = self.current();
+        if pattern.word_len() != current.guess.len() {
+            return Err(WordleError::PatternLength {
+                pattern: pattern.to_letter_string(),
+                expected: current.guess.len(),
+            });
+        }
+        if pattern.is_win() {
+            return Ok(());
+        }
+        match current.children.iter().position(|(p, _)| *p == pattern) {
+            Some(child) => {
+                self.path.push(child);
+                Ok(())
+            }
+            None => Err(WordleError::InconsistentFeedback {
+                guess: current.guess.clone(),
+                pattern,
+            }),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.path.clear();
+    }
+
+    /// Play the tree's guesses until a win, which every game ends in.
+    ///
+    /// Fails if `get_feedback` returns a pattern the tree has no branch for.
+    pub fn solve_with_feedback<F>(&mut self, mut get_feedback: F) -> Result<SolveResult, WordleError>
+    where
+        F: FnMut(&str) -> FeedbackPattern,
+    {
+        let mut guesses = Vec::new();
+        loop {
+            let guess = self.next_guess().to_string();
+            let pattern = get_feedback(&guess);
+            guesses.push((guess, pattern));
+            if pattern.is_win() {
+                return Ok(SolveResult { guesses, outcome: SolveOutcome::Solved });
+            }
+            self.apply_feedback(pattern)?;
+        }
+    }
+
+    /// Play for a known target.
+    ///
+    /// Fails if the target is not a valid word of the tree's length, or with
+    /// [`WordleError::NotInTree`] if the tree has no branch leading to it.
+    pub fn solve_for_target(&mut self, target: &str) -> Result<SolveResult, WordleError> {
+        validate_word(target, self.tree.guess.len())?;
+        self.solve_with_feedback(|guess| FeedbackPattern::calculate(guess, target))
+            .map_err(|_| WordleError::NotInTree(target.to_string()))
+    }
+}
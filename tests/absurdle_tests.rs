@@ -0,0 +1,92 @@
+mod common;
+
+use common::{solver, words};
+use wordle_bot::{
+    adversary_response, solve_absurdle, AbsurdleConfig, AbsurdleGame, AdversaryRule, FeedbackPattern, WordleError,
+    WordleSolver,
+};
+
+#[test]
+fn test_adversary_keeps_largest_group() {
+    let solver = solver();
+    let g = solver.guess_index_of("toast").unwrap();
+    let (pattern, kept) = adversary_response(
+        &solver,
+        g,
+        &solver.candidate_indices(),
+        solver.constraints(),
+        AdversaryRule::Largest,
+    );
+
+    let largest = solver
+        .partition(g, &solver.candidate_indices())
+        .into_iter()
+        .map(|(_, bucket)| bucket.len())
+        .max()
+        .unwrap();
+    assert_eq!(kept.len(), largest);
+    for &a in &kept {
+        assert_eq!(solver.pattern_for(g, a), pattern);
+    }
+}
+
+#[test]
+fn test_adversary_only_concedes_forced_win() {
+    let mut solver = WordleSolver::new(words(&["crane", "crate"])).unwrap();
+    let g = solver.guess_index_of("crane").unwrap();
+
+    // With CRANE and CRATE left, guessing CRANE keeps CRATE rather than losing
+    let (pattern, kept) =
+        adversary_response(&solver, g, &solver.candidate_indices(), solver.constraints(), AdversaryRule::Largest);
+    assert_eq!(pattern, FeedbackPattern::calculate("crane", "crate"));
+    assert_eq!(kept.len(), 1);
+    assert_eq!(solver.answers()[kept[0]], "crate");
+
+    // Once only CRATE is left, guessing it wins
+    solver.apply_feedback("crane", pattern).unwrap();
+    let g = solver.guess_index_of("crate").unwrap();
+    let (pattern, _) =
+        adversary_response(&solver, g, &solver.candidate_indices(), solver.constraints(), AdversaryRule::Evasive);
+    assert!(pattern.is_win());
+}
+
+#[test]
+fn test_game() {
+    let solver = solver();
+    let mut game = AbsurdleGame::new(&solver, AdversaryRule::Largest);
+    assert_eq!(game.remaining_count(), 10);
+    assert_eq!(game.guess("zzzzz"), Err(WordleError::NotInDictionary("zzzzz".to_string())));
+
+    let mut guesses = 0;
+    while !game.is_won() {
+        let word = game.possible_answers()[0].to_string();
+        let before = game.remaining_count();
+        let pattern = game.guess(&word).unwrap();
+        guesses += 1;
+        assert!(pattern.is_win() || game.remaining_count() < before);
+        assert!(guesses <= 10);
+    }
+    assert_eq!(game.history().len(), guesses);
+    assert_eq!(game.remaining_count(), 1);
+}
+
+#[test]
+fn test_solve_absurdle() {
+    let solver = solver();
+    for rule in [AdversaryRule::Largest, AdversaryRule::Evasive] {
+        let config = AbsurdleConfig { rule, ..AbsurdleConfig::default() };
+        let steps = solve_absurdle(&solver, config).unwrap();
+        assert!(steps.last().unwrap().1.is_win());
+
+        // Replaying the sequence against the host gives the same feedback
+        let mut game = AbsurdleGame::new(&solver, rule);
+        for (guess, pattern) in &steps {
+            assert_eq!(game.guess(guess).unwrap(), *pattern);
+        }
+        assert!(game.is_won());
+
+        // No shorter sequence is found
+        let shorter = AbsurdleConfig { max_guesses: steps.len() - 1, ..config };
+        assert!(solve_absurdle(&solver, shorter).is_none());
+    }
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use common::{solver, words};
+use wordle_bot::{
+    batch_to_csv, batch_to_json, solve_batch, BatchResult, FeedbackPattern, SolveOutcome, SolveResult, WordleError,
+};
+
+#[test]
+fn test_solve_batch_matches_single_solves() {
+    let solver = solver();
+    let targets = words(&["toast", "crane", "zebra", "beast"]);
+    let results = solve_batch(&targets, |target| solver.clone().solve_for_target(target));
+
+    assert_eq!(results.len(), targets.len());
+    for (result, target) in results.iter().zip(&targets) {
+        assert_eq!(&result.target, target);
+    }
+    assert_eq!(results[0].guesses, solver.clone().solve_for_target("toast").unwrap().guesses);
+    assert!(results[0].is_solved() && results[1].is_solved() && results[3].is_solved());
+
+    assert!(!results[2].is_solved());
+    assert_eq!(results[2].outcome, None);
+    assert!(results[2].guesses.is_empty());
+    assert_eq!(results[2].error, Some(WordleError::NotInDictionary("zebra".to_string())));
+}
+
+#[test]
+fn test_unsolved_game_is_flagged() {
+    let mut solver = solver();
+    solver.set_max_guesses(Some(1));
+    let opener = solver.find_best_guess().unwrap().word;
+    let target = if opener == "toast" { "beast" } else { "toast" };
+
+    let results = solve_batch(&words(&[target]), |target| solver.clone().solve_for_target(target));
+    assert!(!results[0].is_solved());
+    assert_eq!(results[0].outcome, Some(SolveOutcome::Unsolved));
+    assert_eq!(results[0].guesses.len(), 1);
+    assert!(results[0].error.is_none());
+    assert!(batch_to_csv(&results).contains(",1,false,unsolved,"));
+}
+
+#[test]
+fn test_csv_output() {
+    let results = vec![
+        BatchResult::new(
+            "crate",
+            Ok(SolveResult {
+                guesses: vec![
+                    ("crane".to_string(), FeedbackPattern::calculate("crane", "crate")),
+                    ("crate".to_string(), FeedbackPattern::calculate("crate", "crate")),
+                ],
+                outcome: SolveOutcome::Solved,
+            }),
+        ),
+        BatchResult::new("zebra", Err(WordleError::NotInDictionary("zebra".to_string()))),
+    ];
+
+    assert_eq!(
+        batch_to_csv(&results),
+        "target,guesses,solved,outcome,path,patterns,error\n\
+         crate,2,true,solved,crane crate,gggbg ggggg,\n\
+         zebra,0,false,error,,,'zebra' is not in the word list\n"
+    );
+}
+
+#[test]
+fn test_json_output() {
+    let solver = solver();
+    let results = solve_batch(&words(&["roast", "zebra"]), |target| solver.clone().solve_for_target(target));
+    let json: serde_json::Value = serde_json::from_str(&batch_to_json(&results)).unwrap();
+
+    let rows = json.as_array().unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["target"], "roast");
+    assert_eq!(rows[0]["solved"], true);
+    assert_eq!(rows[0]["outcome"], "solved");
+    assert_eq!(rows[0]["guesses"], results[0].guesses.len());
+    assert_eq!(rows[0]["path"].as_array().unwrap().len(), results[0].guesses.len());
+    assert_eq!(rows[0]["patterns"].as_array().unwrap().last().unwrap(), "ggggg");
+    assert!(rows[0].get("error").is_none());
+    assert_eq!(rows[1]["outcome"], "error");
+    assert_eq!(rows[1]["error"], "'zebra' is not in the word list");
+}
+
+#[test]
+fn test_outcome_names_match_serialization() {
+    for outcome in [SolveOutcome::Solved, SolveOutcome::Unsolved, SolveOutcome::Stuck] {
+        assert_eq!(serde_json::to_value(outcome).unwrap(), outcome.name());
+    }
+}
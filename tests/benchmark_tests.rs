@@ -0,0 +1,257 @@
+mod common;
+
+use common::{solver, words};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use wordle_bot::{
+    benchmark, explore_openers, top_entropy_openers, BenchmarkConfig, EntropyStrategy, FeedbackPattern, GuessAnalysis,
+    GuessStrategy, HardMode, MinimaxStrategy, SolveOutcome, WordleError, WordleSolver, MAX_GUESSES,
+};
+
+/// Entropy ranking, except that it guesses FUZZY while every answer remains
+#[derive(Debug)]
+struct Stubborn;
+
+impl GuessStrategy for Stubborn {
+    fn name(&self) -> &'static str {
+        "stubborn"
+    }
+
+    fn rank(&self, solver: &WordleSolver, n: usize) -> Vec<GuessAnalysis> {
+        match solver.guess_index_of("fuzzy") {
+            Some(g) if solver.remaining_count() == solver.answers().len() => vec![solver.analysis(g, 0.0, false)],
+            _ => EntropyStrategy.rank(solver, n),
+        }
+    }
+}
+
+#[test]
+fn test_report_matches_simple_benchmarks() {
+    let solver = solver();
+    let report = benchmark(&solver, &BenchmarkConfig::default()).unwrap();
+
+    assert_eq!(report.games, 10);
+    assert_eq!(report.strategy, "entropy");
+    assert_eq!(report.hard_mode, "off");
+    assert!((report.average_guesses - solver.benchmark_average_guesses()).abs() < 1e-9);
+    assert_eq!(report.distribution, solver.benchmark_guess_distribution());
+    assert_eq!(report.solved + report.failures.len(), report.games);
+
+    let mean = report.average_guesses;
+    let variance = report
+        .distribution
+        .iter()
+        .map(|&(guesses, games)| games as f64 * (guesses as f64 - mean).powi(2))
+        .sum::<f64>()
+        / report.games as f64;
+    assert!((report.std_dev - variance.sqrt()).abs() < 1e-9);
+
+    let max = report.distribution.last().unwrap().0;
+    assert_eq!(report.percentiles[&99], max);
+    assert!(report.percentiles.values().zip(report.percentiles.values().skip(1)).all(|(a, b)| a <= b));
+}
+
+#[test]
+fn test_hardest_words() {
+    let solver = solver();
+    let config = BenchmarkConfig {
+        hardest: 3,
+        ..BenchmarkConfig::default()
+    };
+    let report = benchmark(&solver, &config).unwrap();
+
+    assert_eq!(report.hardest.len(), 3);
+    assert!(report.hardest.windows(2).all(|w| w[0].guesses.len() >= w[1].guesses.len()));
+    let max = report.distribution.last().unwrap().0;
+    assert_eq!(report.hardest[0].guesses.len(), max);
+
+    let hardest = &report.hardest[0];
+    let mut replay = solver.clone();
+    assert_eq!(replay.solve_for_target(&hardest.answer).unwrap().guesses, hardest.guesses);
+}
+
+#[test]
+fn test_openers_and_settings() {
+    let solver = solver();
+    let config = BenchmarkConfig {
+        strategy: Some(Arc::new(MinimaxStrategy)),
+        hard_mode: Some(HardMode::Strict),
+        openers: words(&["crane", "toast"]),
+        ..BenchmarkConfig::default()
+    };
+    let report = benchmark(&solver, &config).unwrap();
+
+    assert_eq!(report.strategy, "minimax");
+    assert_eq!(report.hard_mode, "strict");
+    assert_eq!(report.games, 20);
+    assert_eq!(report.openers.len(), 2);
+    for stats in &report.openers {
+        assert_eq!(stats.games, 10);
+        assert!(stats.opener == "crane" || stats.opener == "toast");
+    }
+    let (first, second) = (&report.openers[0], &report.openers[1]);
+    assert!((first.failures, first.average_guesses) <= (second.failures, second.average_guesses));
+
+    // The solver passed in is left alone
+    assert_eq!(solver.strategy().name(), "entropy");
+    assert!(!solver.is_hard_mode());
+}
+
+#[test]
+fn test_uninformative_opener_is_not_stuck() {
+    let answers = words(&["crane", "slate", "trace", "crate", "toast", "roast", "beast", "stare"]);
+    let mut guesses = answers.clone();
+    guesses.push("fuzzy".to_string());
+    let solver = WordleSolver::with_answers(answers, guesses).unwrap();
+
+    let config = BenchmarkConfig {
+        openers: words(&["fuzzy"]),
+        ..BenchmarkConfig::default()
+    };
+    let report = benchmark(&solver, &config).unwrap();
+    assert_eq!(report.solved, 8);
+    assert!(report.hardest.iter().all(|game| game.outcome == SolveOutcome::Solved));
+    assert_eq!(report.openers[0].failures, 0);
+
+    // A forced opener plays exactly like the same opening set on the solver
+    let mut opening = solver.clone();
+    opening.set_opening(words(&["fuzzy"])).unwrap();
+    let played = benchmark(&opening, &BenchmarkConfig::default()).unwrap();
+    assert_eq!(played.openers, report.openers);
+    assert_eq!(played.hardest, report.hardest);
+}
+
+#[test]
+fn test_invalid_opener() {
+    let config = BenchmarkConfig {
+        openers: words(&["zebra"]),
+        ..BenchmarkConfig::default()
+    };
+    assert_eq!(
+        benchmark(&solver(), &config).unwrap_err(),
+        WordleError::NotInDictionary("zebra".to_string())
+    );
+}
+
+#[test]
+fn test_report_json() {
+    let config = BenchmarkConfig {
+        hardest: 1,
+        ..BenchmarkConfig::default()
+    };
+    let report = benchmark(&solver(), &config).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+
+    assert_eq!(json["games"], 10);
+    assert_eq!(json["percentiles"]["50"], report.percentiles[&50]);
+    let game = &json["hardest"][0];
+    assert_eq!(game["answer"], report.hardest[0].answer.as_str());
+    assert_eq!(game["guesses"].as_array().unwrap().last().unwrap()[1], "ggggg");
+}
+
+#[test]
+fn test_true_guess_counts_beyond_six() {
+    // Each guess rules out only itself, so the last words take ten guesses
+    let solver = WordleSolver::new(words(&[
+        "bills", "fills", "gills", "hills", "kills", "mills", "pills", "sills", "tills", "wills",
+    ])).unwrap();
+    let report = benchmark(&solver, &BenchmarkConfig::default()).unwrap();
+    assert_eq!(report.guess_limit, MAX_GUESSES);
+    assert_eq!(report.distribution.last().unwrap().0, 10);
+    assert_eq!(report.failures.len(), 4);
+    assert_eq!(report.solved, 6);
+    assert!(report.failures.iter().all(|game| game.outcome == SolveOutcome::Solved));
+    assert_eq!(report.hardest[0].guesses.len(), 10);
+
+    let capped = BenchmarkConfig {
+        max_guesses: Some(MAX_GUESSES),
+        ..BenchmarkConfig::default()
+    };
+    let report = benchmark(&solver, &capped).unwrap();
+    assert_eq!(report.distribution.last().unwrap(), &(6, 5));
+    assert_eq!(report.failures.len(), 4);
+    assert!(report.failures.iter().all(|game| game.outcome == SolveOutcome::Unsolved));
+
+    // A longer limit lets games past six guesses succeed
+    let longer = BenchmarkConfig {
+        max_guesses: Some(8),
+        ..BenchmarkConfig::default()
+    };
+    let report = benchmark(&solver, &longer).unwrap();
+    assert_eq!(report.guess_limit, 8);
+    assert_eq!(report.solved, 8);
+    assert_eq!(report.failures.len(), 2);
+    assert!(report.failures.iter().all(|game| game.outcome == SolveOutcome::Unsolved));
+    assert_eq!(report.openers[0].failures, 2);
+}
+
+#[test]
+fn test_top_entropy_openers() {
+    let mut solver = solver();
+    solver.apply_feedback("crane", FeedbackPattern::calculate("crane", "toast")).unwrap();
+    solver.set_strategy(Arc::new(MinimaxStrategy));
+
+    // Ranked by entropy for a fresh game, whatever the solver's state
+    let fresh = WordleSolver::new(solver.answers().to_vec()).unwrap();
+    let expected: Vec<String> = fresh.find_best_guesses(3).into_iter().map(|a| a.word).collect();
+    assert_eq!(top_entropy_openers(&solver, 3), expected);
+}
+
+#[test]
+fn test_explore_openers() {
+    let solver = solver();
+    let openers = words(&["toast", "crane", "beast", "raise"]);
+    let calls = AtomicUsize::new(0);
+    let stats = explore_openers(&solver, &openers, &BenchmarkConfig::default(), |done, total| {
+        assert!(done >= 1 && done <= total);
+        assert_eq!(total, 4);
+        calls.fetch_add(1, Ordering::Relaxed);
+    })
+    .unwrap();
+    assert_eq!(calls.into_inner(), 4);
+
+    assert_eq!(stats.len(), 4);
+    assert!(stats.windows(2).all(|w| {
+        (w[0].failures, w[0].average_guesses, w[0].max_guesses) <= (w[1].failures, w[1].average_guesses, w[1].max_guesses)
+    }));
+    for opener in &stats {
+        let config = BenchmarkConfig {
+            openers: vec![opener.opener.clone()],
+            ..BenchmarkConfig::default()
+        };
+        assert_eq!(&benchmark(&solver, &config).unwrap().openers[0], opener);
+    }
+
+    let err = explore_openers(&solver, &words(&["zebra"]), &BenchmarkConfig::default(), |_, _| {}).unwrap_err();
+    assert_eq!(err, WordleError::NotInDictionary("zebra".to_string()));
+}
+
+#[test]
+fn test_openers_that_fail_rank_last() {
+    let answers = words(&["crane", "slate", "trace", "crate", "toast", "roast", "beast", "stare"]);
+    let mut guesses = answers.clone();
+    guesses.push("fuzzy".to_string());
+    let mut solver = WordleSolver::with_answers(answers, guesses).unwrap();
+    solver.set_strategy(Arc::new(Stubborn));
+
+    // Every FUZZY game gets stuck after two guesses, no more than the others average
+    let openers = words(&["fuzzy", "crane", "toast", "beast"]);
+    let stats = explore_openers(&solver, &openers, &BenchmarkConfig::default(), |_, _| {}).unwrap();
+    let last = stats.last().unwrap();
+    assert_eq!(last.opener, "fuzzy");
+    assert_eq!(last.failures, 8);
+    assert!(stats.iter().any(|opener| opener.average_guesses > last.average_guesses));
+    assert!(stats[..3].iter().all(|opener| opener.failures == 0));
+}
+
+#[test]
+fn test_benchmark_plays_solver_opening() {
+    let mut solver = solver();
+    solver.set_opening(words(&["toast"])).unwrap();
+    let report = benchmark(&solver, &BenchmarkConfig::default()).unwrap();
+
+    assert_eq!(report.openers.len(), 1);
+    assert_eq!(report.openers[0].opener, "toast");
+    assert_eq!(report.openers[0].games, 10);
+    assert!((report.average_guesses - solver.benchmark_average_guesses()).abs() < 1e-9);
+}
@@ -0,0 +1,53 @@
+use wordle_bot::CandidateSet;
+
+#[test]
+fn test_insert_remove_contains() {
+    let mut set = CandidateSet::new(130);
+    assert!(set.is_empty());
+    assert!(set.insert(0));
+    assert!(set.insert(64));
+    assert!(set.insert(129));
+    assert!(!set.insert(64));
+    assert_eq!(set.len(), 3);
+
+    assert!(set.contains(129));
+    assert!(!set.contains(1));
+    assert!(!set.contains(500));
+
+    assert!(set.remove(64));
+    assert!(!set.remove(64));
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0, 129]);
+}
+
+#[test]
+fn test_full_set() {
+    for capacity in [0, 1, 63, 64, 65, 200] {
+        let set = CandidateSet::full(capacity);
+        assert_eq!(set.len(), capacity);
+        assert_eq!(set.iter().collect::<Vec<_>>(), (0..capacity).collect::<Vec<_>>());
+        assert!(!set.contains(capacity));
+        assert_eq!(set, CandidateSet::from_indices(capacity, 0..capacity));
+    }
+}
+
+#[test]
+fn test_intersection_and_union() {
+    let evens = CandidateSet::from_indices(100, (0..100).step_by(2));
+    let threes = CandidateSet::from_indices(100, (0..100).step_by(3));
+
+    let both = evens.intersection(&threes);
+    assert_eq!(both.iter().collect::<Vec<_>>(), (0..100).step_by(6).collect::<Vec<_>>());
+    assert_eq!(both.len(), 17);
+
+    let mut either = evens.clone();
+    either.union_with(&threes);
+    assert_eq!(either.len(), 50 + 34 - 17);
+    assert!(either.contains(9) && either.contains(10) && !either.contains(11));
+}
+
+#[test]
+fn test_words() {
+    let words: Vec<String> = ["crane", "slate", "trace"].iter().map(|w| w.to_string()).collect();
+    let set = CandidateSet::from_indices(3, [2, 0]);
+    assert_eq!(set.words(&words).collect::<Vec<_>>(), ["crane", "trace"]);
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use common::get_test_words;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use wordle_bot::{
+    benchmark, BenchmarkConfig, EntropyStrategy, GuessAnalysis, GuessStrategy, HardMode, MinimaxStrategy,
+    WordleSolver,
+};
+
+/// Entropy ranking that counts how often it is asked
+#[derive(Debug, Default)]
+struct Counting(AtomicUsize);
+
+impl GuessStrategy for Counting {
+    fn name(&self) -> &'static str {
+        "counting"
+    }
+
+    fn rank(&self, solver: &WordleSolver, n: usize) -> Vec<GuessAnalysis> {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        EntropyStrategy.rank(solver, n)
+    }
+}
+
+fn counting_solver() -> (WordleSolver, Arc<Counting>) {
+    let strategy = Arc::new(Counting::default());
+    let mut solver = WordleSolver::new(get_test_words()).unwrap();
+    solver.set_strategy(strategy.clone());
+    (solver, strategy)
+}
+
+#[test]
+fn test_clones_share_decisions() {
+    let (solver, strategy) = counting_solver();
+    let first = solver.find_best_guess().unwrap();
+    let again = solver.clone().find_best_guess().unwrap();
+
+    assert_eq!(first.word, again.word);
+    assert_eq!(strategy.0.load(Ordering::Relaxed), 1);
+    assert_eq!(solver.decision_cache().len(), 1);
+    assert_eq!(solver.decision_cache().hits(), 1);
+    assert_eq!(solver.decision_cache().misses(), 1);
+}
+
+#[test]
+fn test_benchmark_ranks_each_state_once() {
+    let (solver, strategy) = counting_solver();
+    let average = solver.benchmark_average_guesses();
+    let ranked = strategy.0.load(Ordering::Relaxed);
+    assert!(!solver.decision_cache().is_empty());
+
+    // A second run is answered entirely from the cache
+    let report = benchmark(&solver, &BenchmarkConfig::default()).unwrap();
+    assert_eq!(strategy.0.load(Ordering::Relaxed), ranked);
+    assert!((report.average_guesses - average).abs() < 1e-9);
+}
+
+#[test]
+fn test_settings_start_a_new_cache() {
+    let (mut solver, strategy) = counting_solver();
+    solver.find_best_guess();
+
+    solver.set_hard_mode_rules(HardMode::Strict);
+    assert!(solver.decision_cache().is_empty());
+    solver.find_best_guess();
+    assert_eq!(strategy.0.load(Ordering::Relaxed), 2);
+
+    solver.set_strategy(Arc::new(MinimaxStrategy));
+    assert!(solver.decision_cache().is_empty());
+    assert_eq!(
+        solver.find_best_guess().unwrap().word,
+        MinimaxStrategy.rank(&solver, 1)[0].word
+    );
+}
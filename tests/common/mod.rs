@@ -0,0 +1,20 @@
+//! Word lists and solvers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use wordle_bot::WordleSolver;
+
+/// Owned copies of `list`
+pub fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+/// The ten-word list most tests play with
+pub fn get_test_words() -> Vec<String> {
+    words(&["crane", "slate", "trace", "crate", "raise", "arise", "stare", "roast", "toast", "beast"])
+}
+
+/// A solver where every word of [`get_test_words`] is both a guess and an answer
+pub fn solver() -> WordleSolver {
+    WordleSolver::new(get_test_words()).unwrap()
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use common::solver;
+use wordle_bot::{FeedbackPattern, HardMode, HardModeConstraints, WordleSolver};
+
+fn constraints_after(guesses: &[(&str, &str)]) -> HardModeConstraints {
+    let mut constraints = HardModeConstraints::new();
+    for (guess, target) in guesses {
+        constraints.update(guess, FeedbackPattern::calculate(guess, target));
+    }
+    constraints
+}
+
+#[test]
+fn test_empty_constraints() {
+    let constraints = HardModeConstraints::new();
+    assert!(constraints.is_empty());
+    assert!(constraints.is_valid("crane"));
+    assert!(constraints.is_valid_strict("crane"));
+}
+
+#[test]
+fn test_green_positions_required() {
+    let constraints = constraints_after(&[("crane", "crate")]);
+    assert!(!constraints.is_empty());
+    assert!(constraints.is_valid_strict("crave"));
+    assert!(!constraints.is_valid_strict("trace"));
+}
+
+#[test]
+fn test_yellow_forbidden_in_same_position() {
+    // 'r' is yellow in slot 1
+    let constraints = constraints_after(&[("arose", "rebus")]);
+    assert_eq!(constraints.letters[(b'r' - b'a') as usize].min_count, 1);
+    assert!(constraints.letters[(b'r' - b'a') as usize].is_forbidden_at(1));
+
+    // Strict mode only needs the letter somewhere
+    assert!(constraints.is_valid_strict("fresh"));
+    // Ultra mode rejects 'r' back in slot 1 and requires the yellow 's' and 'e'
+    assert!(!constraints.is_valid("press"));
+    assert!(constraints.is_valid("rebus"));
+}
+
+#[test]
+fn test_repeated_yellows_imply_min_count() {
+    // Two 'e's revealed as yellow
+    let constraints = constraints_after(&[("eerie", "sheep")]);
+    let e = &constraints.letters[(b'e' - b'a') as usize];
+    assert_eq!(e.min_count, 2);
+    assert_eq!(e.max_count, 2);
+
+    assert!(!constraints.is_valid_strict("cable"));
+    assert!(constraints.is_valid_strict("geese"));
+    assert!(constraints.is_valid("sheep"));
+    assert!(!constraints.is_valid("geese"));
+}
+
+#[test]
+fn test_gray_after_green_caps_count() {
+    // Second 'e' is gray, so the answer has exactly one 'e'
+    let constraints = constraints_after(&[("speed", "spend")]);
+    let e = &constraints.letters[(b'e' - b'a') as usize];
+    assert_eq!(e.min_count, 1);
+    assert_eq!(e.max_count, 1);
+
+    assert!(constraints.is_valid("spend"));
+    assert!(!constraints.is_valid("sweep"));
+}
+
+#[test]
+fn test_gray_letter_excluded_in_ultra_only() {
+    let constraints = constraints_after(&[("crane", "toast")]);
+    assert!(constraints.is_valid_strict("crane"));
+    assert!(!constraints.is_valid("crane"));
+    assert!(constraints.is_valid("toast"));
+}
+
+#[test]
+fn test_allows_by_mode() {
+    let constraints = constraints_after(&[("crane", "toast")]);
+    assert!(constraints.allows("salty", HardMode::Off));
+    assert!(!constraints.allows("salty", HardMode::Strict));
+    assert!(constraints.allows("beach", HardMode::Strict));
+    assert!(!constraints.allows("beach", HardMode::Ultra));
+}
+
+#[test]
+fn test_hard_mode_names() {
+    assert_eq!(HardMode::from_name("ultra"), Some(HardMode::Ultra));
+    assert_eq!(HardMode::from_name("STRICT"), Some(HardMode::Strict));
+    assert_eq!(HardMode::from_name("off"), Some(HardMode::Off));
+    assert_eq!(HardMode::from_name("bogus"), None);
+    assert_eq!(HardMode::Ultra.name(), "ultra");
+}
+
+#[test]
+fn test_ultra_mode_guesses_are_candidates() {
+    let mut solver = solver();
+    solver.set_hard_mode_rules(HardMode::Ultra);
+    assert!(solver.is_hard_mode());
+
+    let pattern = FeedbackPattern::calculate("slate", "roast");
+    solver.apply_feedback("slate", pattern).unwrap();
+
+    let remaining = solver.possible_answers().len();
+    let guesses = solver.find_best_guesses(10);
+    assert!(guesses.len() <= remaining.max(1));
+    for g in &guesses {
+        assert!(solver.constraints().is_valid(&g.word), "Ultra mode violation: {}", g.word);
+    }
+}
+
+#[test]
+fn test_constraints_tracked_before_enabling_hard_mode() {
+    let words: Vec<String> = ["crane", "crate", "trace", "slate"].iter().map(|s| s.to_string()).collect();
+    let mut solver = WordleSolver::new(words).unwrap();
+
+    solver.apply_feedback("crane", FeedbackPattern::calculate("crane", "crate")).unwrap();
+    assert!(!solver.constraints().is_empty());
+
+    solver.set_hard_mode(true);
+    assert_eq!(solver.hard_mode_rules(), HardMode::Strict);
+    for g in solver.find_best_guesses(10) {
+        assert!(g.word.starts_with("cra"), "Hard mode violation: {}", g.word);
+    }
+}
+
+#[test]
+fn test_invalid_words_rejected() {
+    let mut constraints = HardModeConstraints::new();
+    let pattern = FeedbackPattern::parse("gbbbb").unwrap();
+    assert!(constraints.try_update("CRANE", pattern).is_err());
+    assert!(constraints.try_update("cranes", pattern).is_err());
+    assert!(constraints.is_empty());
+
+    assert!(!constraints.is_valid("CRANE"));
+    assert!(!constraints.is_valid_strict("cr4ne"));
+}
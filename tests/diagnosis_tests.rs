@@ -0,0 +1,112 @@
+mod common;
+
+use common::{solver, words};
+use wordle_bot::{
+    diagnose, diagnose_steps, load_dictionary_with_length, Feedback, FeedbackPattern, WordleError, WordleSolver,
+};
+
+#[test]
+fn test_finds_conflicting_steps() {
+    let mut solver = solver();
+    solver.apply_feedback("crane", FeedbackPattern::calculate("crane", "toast")).unwrap();
+    solver.apply_feedback("raise", FeedbackPattern::calculate("raise", "toast")).unwrap();
+
+    // TOAST gives ybbyy for STARE; a typo makes the first tile gray
+    let typo = FeedbackPattern::parse("bybyb").unwrap();
+    assert!(solver.clone().apply_feedback("stare", typo).is_err());
+
+    let diagnosis = diagnose(&solver, "stare", typo).unwrap();
+    assert!(!diagnosis.is_consistent());
+    assert_eq!(diagnosis.steps.len(), 3);
+    assert!(diagnosis.conflict.contains(&2));
+
+    // Dropping any conflicting step leaves some answer
+    for &skip in &diagnosis.conflict {
+        let rest: Vec<_> = diagnosis
+            .conflict
+            .iter()
+            .filter(|&&s| s != skip)
+            .map(|&s| diagnosis.steps[s].clone())
+            .collect();
+        assert!(diagnose_steps(&solver, rest).unwrap().is_consistent());
+    }
+}
+
+#[test]
+fn test_suggests_single_tile_corrections() {
+    let mut solver = solver();
+    solver.apply_feedback("crane", FeedbackPattern::calculate("crane", "crate")).unwrap();
+    let actual = FeedbackPattern::calculate("slate", "crate");
+    let mut tiles = actual.to_feedbacks();
+    tiles[4] = Feedback::Absent;
+    let typo = FeedbackPattern::new(tiles);
+
+    let diagnosis = diagnose(&solver, "slate", typo).unwrap();
+    let correction = diagnosis
+        .corrections
+        .iter()
+        .find(|c| c.step == 1 && c.position == 4 && c.to == Feedback::Correct)
+        .expect("the typo is among the corrections");
+    assert_eq!(correction.from, Feedback::Absent);
+    assert_eq!(correction.remaining, 1);
+    assert_eq!(
+        correction.to_string(),
+        "if tile 5 of SLATE were green instead of gray, 1 word remains"
+    );
+    assert!(diagnosis.corrections.windows(2).all(|w| w[0].remaining >= w[1].remaining));
+}
+
+#[test]
+fn test_answer_missing_from_answer_list() {
+    let answers = words(&["crane", "slate", "trace"]);
+    let guesses = words(&["crane", "slate", "trace", "toast", "roast"]);
+    let solver = WordleSolver::with_answers(answers, guesses).unwrap();
+
+    let pattern = FeedbackPattern::calculate("slate", "toast");
+    let diagnosis = diagnose(&solver, "slate", pattern).unwrap();
+    assert_eq!(diagnosis.conflict, vec![0]);
+    assert_eq!(diagnosis.unlisted_matches, words(&["toast", "roast"]));
+    assert!(diagnosis.mismatches("toast").unwrap().is_empty());
+    let expected = FeedbackPattern::calculate("slate", "slate");
+    assert_eq!(diagnosis.mismatches("slate").unwrap(), vec![(0, expected)]);
+    assert!(diagnosis.mismatches("SLATE").is_err());
+}
+
+#[test]
+fn test_answer_missing_from_shared_list() {
+    // Every guess is an answer, so the embedded dictionary is searched instead
+    let solver = WordleSolver::new(words(&["crane", "slate", "trace"])).unwrap();
+    let pattern = FeedbackPattern::calculate("slate", "toast");
+    let diagnosis = diagnose(&solver, "slate", pattern).unwrap();
+
+    assert!(diagnosis.unlisted_words > 0);
+    assert!(diagnosis.unlisted_matches.contains(&"toast".to_string()));
+    for word in &diagnosis.unlisted_matches {
+        assert_eq!(FeedbackPattern::calculate("slate", word), pattern);
+        assert!(!solver.answers().contains(word));
+    }
+
+    // With the whole dictionary as answers there is nothing left to check
+    let dictionary = load_dictionary_with_length(5);
+    let solver = WordleSolver::new(dictionary).unwrap();
+    let diagnosis = diagnose(&solver, "slate", FeedbackPattern::parse("ggggy").unwrap()).unwrap();
+    assert_eq!(diagnosis.unlisted_words, 0);
+    assert!(diagnosis.unlisted_matches.is_empty());
+}
+
+#[test]
+fn test_consistent_and_invalid_input() {
+    let solver = solver();
+    let diagnosis = diagnose(&solver, "crane", FeedbackPattern::calculate("crane", "crate")).unwrap();
+    assert!(diagnosis.is_consistent());
+    assert!(diagnosis.corrections.is_empty());
+
+    assert!(matches!(
+        diagnose(&solver, "Crane", FeedbackPattern::parse("bbbbb").unwrap()),
+        Err(WordleError::InvalidWord { .. })
+    ));
+    assert!(matches!(
+        diagnose(&solver, "crane", FeedbackPattern::parse_with_length("bbbb", 4).unwrap()),
+        Err(WordleError::PatternLength { .. })
+    ));
+}
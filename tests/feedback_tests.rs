@@ -1,4 +1,4 @@
-use wordle_bot::{Feedback, FeedbackPattern};
+use wordle_bot::{Feedback, FeedbackPattern, WordleError, WORD_LENGTH};
 
 #[test]
 fn test_all_correct() {
@@ -67,7 +67,7 @@ fn test_duplicate_guess_limited_target() {
 #[test]
 fn test_pattern_encoding_decoding() {
     for pattern_val in 0..FeedbackPattern::NUM_PATTERNS {
-        let pattern = FeedbackPattern(pattern_val as u8);
+        let pattern = FeedbackPattern::from_code(pattern_val as u16, WORD_LENGTH);
         let feedbacks = pattern.to_feedbacks();
         let reconstructed = FeedbackPattern::new(feedbacks);
         assert_eq!(pattern, reconstructed);
@@ -90,9 +90,9 @@ fn test_pattern_parse() {
 
 #[test]
 fn test_pattern_parse_invalid() {
-    assert!(FeedbackPattern::parse("gybbb1").is_none());
-    assert!(FeedbackPattern::parse("gybb").is_none());
-    assert!(FeedbackPattern::parse("gybzb").is_none());
+    assert!(FeedbackPattern::parse("gybbb1").is_err());
+    assert!(FeedbackPattern::parse("gybb").is_err());
+    assert!(FeedbackPattern::parse("gybzb").is_err());
 }
 
 #[test]
@@ -116,4 +116,95 @@ fn test_specific_wordle_cases() {
     assert_eq!(feedbacks[2], Feedback::Absent);
     assert_eq!(feedbacks[3], Feedback::Present);
     assert_eq!(feedbacks[4], Feedback::Absent);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_four_letter_feedback() {
+    let pattern = FeedbackPattern::calculate("star", "stir");
+    assert_eq!(pattern.word_len(), 4);
+    let feedbacks = pattern.to_feedbacks();
+    assert_eq!(feedbacks.len(), 4);
+    assert_eq!(feedbacks[0], Feedback::Correct);
+    assert_eq!(feedbacks[1], Feedback::Correct);
+    assert_eq!(feedbacks[2], Feedback::Absent);
+    assert_eq!(feedbacks[3], Feedback::Correct);
+
+    let anagram = FeedbackPattern::calculate("star", "rats");
+    assert_eq!(anagram, FeedbackPattern::parse_with_length("yyyy", 4).unwrap());
+}
+
+#[test]
+fn test_seven_letter_win() {
+    let pattern = FeedbackPattern::calculate("example", "example");
+    assert!(pattern.is_win());
+    assert_eq!(pattern, FeedbackPattern::all_correct(7));
+    assert_eq!(pattern.index(), FeedbackPattern::num_patterns(7) - 1);
+    assert_ne!(pattern, FeedbackPattern::ALL_CORRECT);
+}
+
+#[test]
+fn test_long_pattern_encoding_decoding() {
+    for len in [4, 6, 7, 10] {
+        for code in [0, 1, FeedbackPattern::num_patterns(len) / 2, FeedbackPattern::num_patterns(len) - 1] {
+            let pattern = FeedbackPattern::from_code(code as u16, len);
+            let feedbacks = pattern.to_feedbacks();
+            assert_eq!(feedbacks.len(), len);
+            assert_eq!(FeedbackPattern::new(feedbacks), pattern);
+        }
+    }
+}
+
+#[test]
+fn test_pattern_parse_with_length() {
+    let pattern = FeedbackPattern::parse_with_length("gybbbg", 6).unwrap();
+    assert_eq!(pattern.word_len(), 6);
+    assert_eq!(pattern.to_feedbacks()[5], Feedback::Correct);
+
+    assert!(FeedbackPattern::parse_with_length("gybbb", 6).is_err());
+    assert!(FeedbackPattern::parse_with_length("gggggggggggg", 12).is_err());
+}
+
+#[test]
+fn test_pattern_parse_errors() {
+    assert_eq!(
+        FeedbackPattern::parse("gybzb").unwrap_err(),
+        WordleError::InvalidPattern { pattern: "gybzb".to_string(), position: 3 }
+    );
+    assert_eq!(
+        FeedbackPattern::parse("gyb").unwrap_err(),
+        WordleError::PatternLength { pattern: "gyb".to_string(), expected: 5 }
+    );
+    assert_eq!(
+        FeedbackPattern::parse("gybzb").unwrap_err().to_string(),
+        "invalid feedback 'gybzb': 'z' at position 4 is not g, y or b"
+    );
+    assert_eq!(
+        FeedbackPattern::parse("gyb").unwrap_err().to_string(),
+        "feedback 'gyb' has 3 letters, expected 5"
+    );
+}
+
+#[test]
+fn test_try_calculate() {
+    assert_eq!(
+        FeedbackPattern::try_calculate("crane", "crate").unwrap(),
+        FeedbackPattern::parse("gggbg").unwrap()
+    );
+    assert_eq!(
+        FeedbackPattern::try_calculate("Crane", "crate").unwrap_err().to_string(),
+        "invalid word 'Crane': 'C' at position 1 is not a lowercase letter"
+    );
+    assert_eq!(
+        FeedbackPattern::try_calculate("crane", "crates").unwrap_err().to_string(),
+        "'crates' has 6 letters, expected 5"
+    );
+    assert!(FeedbackPattern::try_calculate("cr@ne", "crate").is_err());
+    assert!(FeedbackPattern::try_calculate("crane", "cr\u{e9}te").is_err());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_calculate_asserts_validated_words() {
+    FeedbackPattern::calculate("Crane", "crate");
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::{get_test_words, solver};
+use std::sync::Arc;
+use wordle_bot::{strategy_from_name, FeedbackPattern, LookaheadConfig, LookaheadStrategy, WordleSolver};
+
+fn lookahead_solver(words: Vec<String>, depth: usize, breadth: usize) -> WordleSolver {
+    let mut solver = WordleSolver::new(words).unwrap();
+    solver.set_strategy(Arc::new(LookaheadStrategy::new(LookaheadConfig { depth, breadth })));
+    solver
+}
+
+#[test]
+fn test_lookahead_by_name() {
+    let strategy = strategy_from_name("lookahead").unwrap();
+    assert_eq!(strategy.name(), "lookahead");
+    assert_eq!(LookaheadStrategy::default().config, LookaheadConfig::default());
+}
+
+#[test]
+fn test_lookahead_ranks_by_expected_guesses() {
+    let solver = lookahead_solver(get_test_words(), 2, 10);
+    let top = solver.find_best_guesses(5);
+
+    assert_eq!(top.len(), 5);
+    for i in 1..top.len() {
+        let prev = top[i - 1].expected_guesses.unwrap();
+        let next = top[i].expected_guesses.unwrap();
+        assert!(prev <= next);
+    }
+    // At least one guess, at most one per possible answer
+    let best = top[0].expected_guesses.unwrap();
+    assert!((1.0..=10.0).contains(&best));
+}
+
+#[test]
+fn test_entropy_strategy_leaves_expected_guesses_empty() {
+    let solver = solver();
+    assert!(solver.find_best_guess().unwrap().expected_guesses.is_none());
+}
+
+#[test]
+fn test_exhaustive_lookahead_matches_benchmark() {
+    // With enough depth and breadth the search covers the whole game, so the
+    // predicted cost of the opener is exactly the average the solver achieves
+    let solver = lookahead_solver(get_test_words(), 6, 100);
+    let predicted = solver.find_best_guess().unwrap().expected_guesses.unwrap();
+    let actual = solver.benchmark_average_guesses();
+
+    assert!((predicted - actual).abs() < 1e-9, "{} vs {}", predicted, actual);
+}
+
+#[test]
+fn test_lookahead_not_worse_than_entropy() {
+    let entropy = solver();
+    let lookahead = lookahead_solver(get_test_words(), 6, 100);
+
+    assert!(lookahead.benchmark_average_guesses() <= entropy.benchmark_average_guesses() + 1e-9);
+}
+
+#[test]
+fn test_lookahead_solves_all_targets() {
+    let words = get_test_words();
+    for target in &words {
+        let mut solver = lookahead_solver(words.clone(), 2, 5);
+        let guesses = solver.solve_for_target(target).unwrap().guesses;
+
+        let (final_guess, final_pattern) = guesses.last().unwrap();
+        assert!(final_pattern.is_win(), "Didn't win for target: {}", target);
+        assert_eq!(final_guess, target);
+    }
+}
+
+#[test]
+fn test_lookahead_respects_hard_mode() {
+    let mut solver = lookahead_solver(get_test_words(), 2, 10);
+    solver.set_hard_mode(true);
+    solver.apply_feedback("crane", FeedbackPattern::calculate("crane", "crate")).unwrap();
+
+    for g in solver.find_best_guesses(10) {
+        assert!(g.word.starts_with("cra"), "Hard mode violation: {}", g.word);
+    }
+}
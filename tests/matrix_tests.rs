@@ -0,0 +1,222 @@
+mod common;
+
+use common::{get_test_words, solver};
+use std::sync::Arc;
+use wordle_bot::{CacheStatus, FeedbackPattern, PatternMatrix, WordleError, WordleSolver};
+
+#[test]
+fn test_matrix_matches_calculate() {
+    let guesses = get_test_words();
+    let answers: Vec<String> = guesses[3..].to_vec();
+    let matrix = PatternMatrix::build(5, &guesses, &answers).unwrap();
+
+    assert_eq!(matrix.num_guesses(), guesses.len());
+    assert_eq!(matrix.num_answers(), answers.len());
+
+    for (g, guess) in guesses.iter().enumerate() {
+        for (a, answer) in answers.iter().enumerate() {
+            assert_eq!(matrix.get(g, a), FeedbackPattern::calculate(guess, answer));
+        }
+    }
+}
+
+#[test]
+fn test_empty_matrix() {
+    let matrix = PatternMatrix::build(5, &get_test_words(), &[]).unwrap();
+    assert_eq!(matrix.num_answers(), 0);
+    assert_eq!(matrix.num_guesses(), 10);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_get_asserts_answer_in_range() {
+    let guesses = get_test_words();
+    let matrix = PatternMatrix::build(5, &guesses, &guesses[..4]).unwrap();
+    // Would otherwise read the first cell of the next guess's row
+    matrix.get(0, 4);
+}
+
+#[test]
+fn test_solver_with_matrix_matches_without() {
+    let words = get_test_words();
+    let plain = WordleSolver::new(words.clone()).unwrap();
+    let mut fast = WordleSolver::new(words.clone()).unwrap();
+    fast.build_pattern_matrix();
+    assert!(fast.pattern_matrix().is_some());
+
+    for word in &words {
+        assert_eq!(plain.calculate_entropy_for_word(word).unwrap(), fast.calculate_entropy_for_word(word).unwrap());
+    }
+
+    for target in &words {
+        let mut a = plain.clone();
+        let mut b = fast.clone();
+        assert_eq!(a.solve_for_target(target).unwrap(), b.solve_for_target(target).unwrap());
+    }
+
+    assert_eq!(plain.benchmark_guess_distribution(), fast.benchmark_guess_distribution());
+}
+
+#[test]
+fn test_set_pattern_matrix_checks_word_lists() {
+    let words = get_test_words();
+    let mut solver = solver();
+
+    let other = PatternMatrix::build(5, &words[1..], &words[1..]).unwrap();
+    assert_eq!(solver.set_pattern_matrix(Arc::new(other)), Err(WordleError::MatrixMismatch));
+    assert!(solver.pattern_matrix().is_none());
+
+    let matching = PatternMatrix::build(5, &words, &words).unwrap();
+    solver.set_pattern_matrix(Arc::new(matching)).unwrap();
+    assert!(solver.pattern_matrix().is_some());
+}
+
+#[test]
+fn test_matrix_filtering() {
+    let mut solver = solver();
+    solver.build_pattern_matrix();
+
+    let pattern = FeedbackPattern::calculate("crane", "crate");
+    solver.apply_feedback("crane", pattern).unwrap();
+
+    assert!(solver.possible_answers().contains(&"crate"));
+    assert!(!solver.possible_answers().contains(&"crane"));
+}
+
+fn temp_cache_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("wordle-bot-test-{}-{}.bin", name, std::process::id()))
+}
+
+#[test]
+fn test_save_and_open_round_trip() {
+    let guesses = get_test_words();
+    let answers: Vec<String> = guesses[..4].to_vec();
+    let path = temp_cache_path("round-trip");
+
+    let built = PatternMatrix::build(5, &guesses, &answers).unwrap();
+    built.save(&path).unwrap();
+
+    let mapped = PatternMatrix::open(&path).unwrap();
+    assert!(mapped.is_mapped());
+    assert!(mapped.matches(&guesses, &answers));
+    assert_eq!(mapped.dictionary_hash(), built.dictionary_hash());
+    for g in 0..guesses.len() {
+        for a in 0..answers.len() {
+            assert_eq!(mapped.get(g, a), built.get(g, a));
+        }
+    }
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_load_or_build_invalidates_on_dictionary_change() {
+    let guesses = get_test_words();
+    let path = temp_cache_path("invalidate");
+    let _ = std::fs::remove_file(&path);
+
+    let (_, status) = PatternMatrix::load_or_build(&path, 5, &guesses, &guesses).unwrap();
+    assert!(matches!(status, CacheStatus::Built));
+    let (_, status) = PatternMatrix::load_or_build(&path, 5, &guesses, &guesses).unwrap();
+    assert!(status.is_cached());
+
+    let mut changed = guesses.clone();
+    changed[0] = "zesty".to_string();
+    let (matrix, status) = PatternMatrix::load_or_build(&path, 5, &changed, &changed).unwrap();
+    assert!(matches!(status, CacheStatus::Built));
+    assert_eq!(matrix.get(0, 0), FeedbackPattern::ALL_CORRECT);
+
+    let (_, status) = PatternMatrix::load_or_build(&path, 5, &changed, &changed).unwrap();
+    assert!(status.is_cached());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_open_rejects_garbage() {
+    let path = temp_cache_path("garbage");
+    std::fs::write(&path, b"definitely not a cache file").unwrap();
+
+    let err = PatternMatrix::open(&path).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_open_rejects_overflowing_sizes() {
+    let guesses = get_test_words();
+    let path = temp_cache_path("overflow");
+    PatternMatrix::build(5, &guesses, &guesses).unwrap().save(&path).unwrap();
+
+    // Six-letter words take two bytes per cell, so the size overflows usize
+    let mut bytes = std::fs::read(&path).unwrap();
+    bytes[12..16].copy_from_slice(&6u32.to_le_bytes());
+    bytes[16..20].copy_from_slice(&u32::MAX.to_le_bytes());
+    bytes[20..24].copy_from_slice(&u32::MAX.to_le_bytes());
+    std::fs::write(&path, &bytes).unwrap();
+
+    let err = PatternMatrix::open(&path).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    let (_, status) = PatternMatrix::load_or_build(&path, 5, &guesses, &guesses).unwrap();
+    assert!(!status.is_cached());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_load_or_build_returns_save_error() {
+    let guesses = get_test_words();
+    let blocker = temp_cache_path("blocker");
+    std::fs::write(&blocker, b"a file, not a directory").unwrap();
+
+    // The cache's directory cannot be created, but the table is still built
+    let path = blocker.join("patterns.bin");
+    let (matrix, status) = PatternMatrix::load_or_build(&path, 5, &guesses, &guesses).unwrap();
+    assert!(matches!(status, CacheStatus::Unsaved(_)));
+    assert!(!matrix.is_mapped());
+    assert_eq!(matrix.get(0, 0), FeedbackPattern::ALL_CORRECT);
+
+    std::fs::remove_file(&blocker).unwrap();
+}
+
+#[test]
+fn test_solver_uses_cached_matrix() {
+    let words = get_test_words();
+    let path = temp_cache_path("solver");
+    let _ = std::fs::remove_file(&path);
+
+    let mut first = WordleSolver::new(words.clone()).unwrap();
+    assert!(!first.load_or_build_pattern_matrix(&path).is_cached());
+
+    let mut second = WordleSolver::new(words).unwrap();
+    assert!(second.load_or_build_pattern_matrix(&path).is_cached());
+    assert!(second.pattern_matrix().unwrap().is_mapped());
+    assert_eq!(first.solve_for_target("toast").unwrap(), second.solve_for_target("toast").unwrap());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_wide_patterns_for_long_words() {
+    let words: Vec<String> = ["example", "exclaim", "explain", "sampler"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let matrix = PatternMatrix::build(7, &words, &words).unwrap();
+    assert_eq!(matrix.word_length(), 7);
+
+    for (g, guess) in words.iter().enumerate() {
+        for (a, answer) in words.iter().enumerate() {
+            assert_eq!(matrix.get(g, a), FeedbackPattern::calculate(guess, answer));
+        }
+    }
+
+    let path = temp_cache_path("wide");
+    matrix.save(&path).unwrap();
+    let mapped = PatternMatrix::open(&path).unwrap();
+    assert_eq!(mapped.word_length(), 7);
+    assert_eq!(mapped.get(3, 0), matrix.get(3, 0));
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use common::solver;
+use wordle_bot::{default_max_guesses, FeedbackPattern, MultiBoardSolver, SolveOutcome, WordleError};
+
+#[test]
+fn test_guess_budgets() {
+    assert_eq!(default_max_guesses(1), 6);
+    assert_eq!(default_max_guesses(2), 7);
+    assert_eq!(default_max_guesses(4), 9);
+    assert_eq!(default_max_guesses(8), 13);
+    assert_eq!(MultiBoardSolver::new(&solver(), 4).unwrap().max_guesses(), Some(9));
+    assert_eq!(MultiBoardSolver::new(&solver(), 0).unwrap_err(), WordleError::NoBoards);
+
+    let mut unlimited = MultiBoardSolver::new(&solver(), 2).unwrap();
+    unlimited.set_max_guesses(None);
+    assert_eq!(unlimited.guesses_left(), None);
+}
+
+#[test]
+fn test_apply_feedback_per_board() {
+    let mut game = MultiBoardSolver::new(&solver(), 2).unwrap();
+    let win = FeedbackPattern::calculate("toast", "toast");
+    let other = FeedbackPattern::calculate("toast", "crane");
+
+    assert_eq!(
+        game.apply_feedback("toast", &[win]),
+        Err(WordleError::FeedbackCount { given: 1, expected: 2 })
+    );
+    game.apply_feedback("toast", &[other, win]).unwrap();
+    assert!(!game.is_solved(0));
+    assert!(game.is_solved(1));
+    assert_eq!(game.unsolved_boards(), [0]);
+    assert_eq!(game.solved_at(), [None, Some(1)]);
+    assert_eq!(game.guesses_left(), Some(6));
+
+    // Only the unsolved board takes feedback now, and a rejection names it
+    let impossible = FeedbackPattern::parse("ggggg").unwrap();
+    let err = game.apply_feedback("beast", &[impossible]).unwrap_err();
+    assert!(matches!(err, WordleError::OnBoard { board: 0, .. }));
+    assert_eq!(game.guesses().len(), 1);
+
+    game.reset();
+    assert_eq!(game.unsolved_boards(), [0, 1]);
+    assert!(game.guesses().is_empty());
+}
+
+#[test]
+fn test_best_guess_solves_a_known_board() {
+    let mut game = MultiBoardSolver::new(&solver(), 2).unwrap();
+    let patterns = [
+        FeedbackPattern::calculate("toast", "crane"),
+        FeedbackPattern::calculate("toast", "beast"),
+    ];
+    game.apply_feedback("toast", &patterns).unwrap();
+    assert_eq!(game.board(0).possible_answers(), ["crane"]);
+    assert_eq!(game.board(1).possible_answers(), ["beast"]);
+
+    let best = game.find_best_guess().unwrap();
+    assert!(best.word == "crane" || best.word == "beast");
+    assert!((best.expected_solved - 1.0).abs() < 1e-9);
+    assert!((best.expected_guesses - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_expected_guesses_share_turns_between_boards() {
+    let one = MultiBoardSolver::new(&solver(), 1).unwrap().find_best_guesses(usize::MAX);
+    let two = MultiBoardSolver::new(&solver(), 2).unwrap().find_best_guesses(usize::MAX);
+
+    // Both boards are played by the same guesses, so a second identical board
+    // only adds the guess its own answer needs, not a second set of turns
+    for analysis in &two {
+        let single = one.iter().find(|a| a.word == analysis.word).unwrap();
+        let shared = single.expected_guesses.max(2.0 * (1.0 - single.expected_solved));
+        assert!((analysis.expected_guesses - shared).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_solve_for_targets() {
+    let targets = ["crane", "toast", "beast", "raise"];
+    let mut game = MultiBoardSolver::new(&solver(), 4).unwrap();
+    let result = game.solve_for_targets(&targets).unwrap();
+
+    assert_eq!(result.outcome, SolveOutcome::Solved);
+    assert!(result.is_solved());
+    assert!(result.num_guesses() <= 9);
+    for (b, target) in targets.iter().enumerate() {
+        let at = result.solved_at[b].unwrap();
+        assert_eq!(result.guesses[at - 1].0, *target);
+        assert!(result.guesses[at..].iter().all(|(_, feedback)| feedback[b].is_none()));
+    }
+
+    let mut short = MultiBoardSolver::new(&solver(), 4).unwrap();
+    short.set_max_guesses(Some(2));
+    assert_eq!(short.solve_for_targets(&targets).unwrap().outcome, SolveOutcome::Unsolved);
+
+    assert_eq!(
+        MultiBoardSolver::new(&solver(), 2).unwrap().solve_for_targets(&["crane", "zzzzz"]),
+        Err(WordleError::NotInDictionary("zzzzz".to_string()))
+    );
+
+    let err = MultiBoardSolver::new(&solver(), 2).unwrap().solve_for_targets(&["crane"]).unwrap_err();
+    assert_eq!(err, WordleError::TargetCount { given: 1, expected: 2 });
+    assert_eq!(err.to_string(), "got 1 target word, expected 2 (one per board)");
+}
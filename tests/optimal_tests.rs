@@ -0,0 +1,137 @@
+mod common;
+
+use common::{get_test_words, solver};
+use std::sync::Arc;
+use wordle_bot::{
+    optimal_tree, DecisionTree, FeedbackPattern, HardMode, HardModeConstraints, LookaheadConfig,
+    LookaheadStrategy, OptimalConfig, WordleSolver,
+};
+
+fn unlimited() -> OptimalConfig {
+    OptimalConfig {
+        max_depth: None,
+        breadth: None,
+    }
+}
+
+/// Follow the tree for `target`, returning the guesses played
+fn play(tree: &DecisionTree, target: &str) -> Vec<String> {
+    let mut guesses = Vec::new();
+    let mut node = Some(tree);
+    while let Some(current) = node {
+        guesses.push(current.guess.clone());
+        let pattern = FeedbackPattern::calculate(&current.guess, target);
+        if pattern.is_win() {
+            break;
+        }
+        node = current.next(pattern);
+    }
+    guesses
+}
+
+#[test]
+fn test_optimal_tree_finds_every_answer() {
+    let words = get_test_words();
+    let solver = WordleSolver::new(words.clone()).unwrap();
+    let tree = optimal_tree(&solver, OptimalConfig::default()).unwrap();
+
+    assert_eq!(tree.num_answers(), words.len());
+    let mut total = 0;
+    for target in &words {
+        let guesses = play(&tree, target);
+        assert_eq!(guesses.last().unwrap(), target);
+        total += guesses.len();
+    }
+    assert_eq!(total, tree.total_guesses());
+    assert!(tree.max_depth() <= 6);
+}
+
+#[test]
+fn test_optimal_matches_exhaustive_lookahead() {
+    let words = get_test_words();
+    let tree = optimal_tree(&WordleSolver::new(words.clone()).unwrap(), unlimited()).unwrap();
+
+    // Searching every guess to full depth is exact, just without pruning tricks
+    let mut solver = WordleSolver::new(words.clone()).unwrap();
+    let config = LookaheadConfig {
+        depth: words.len(),
+        breadth: words.len(),
+    };
+    solver.set_strategy(Arc::new(LookaheadStrategy::new(config)));
+    let expected = solver.find_best_guess().unwrap().expected_guesses.unwrap();
+
+    assert!((tree.average_guesses() - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_optimal_beats_strategies() {
+    let answers: Vec<String> = wordle_bot::load_dictionary().into_iter().take(40).collect();
+    let solver = WordleSolver::new(answers).unwrap();
+
+    let optimal = optimal_tree(&solver, OptimalConfig::default()).unwrap();
+    let entropy = DecisionTree::from_strategy(&solver).unwrap();
+
+    assert!(optimal.total_guesses() <= entropy.total_guesses());
+    assert_eq!(entropy.num_answers(), optimal.num_answers());
+    assert!(
+        (entropy.average_guesses() - solver.benchmark_average_guesses()).abs() < 1e-9,
+        "The strategy tree should replay the benchmark games"
+    );
+}
+
+#[test]
+fn test_max_depth_is_respected() {
+    let words = get_test_words();
+    let solver = WordleSolver::new(words).unwrap();
+
+    let free = optimal_tree(&solver, unlimited()).unwrap();
+    assert!(optimal_tree(&solver, OptimalConfig { max_depth: Some(1), breadth: None }).is_none());
+
+    let limited = OptimalConfig {
+        max_depth: Some(free.max_depth()),
+        breadth: None,
+    };
+    let tree = optimal_tree(&solver, limited).unwrap();
+    assert!(tree.max_depth() <= free.max_depth());
+    assert_eq!(tree.total_guesses(), free.total_guesses());
+}
+
+#[test]
+fn test_breadth_limit_still_solves() {
+    let words = get_test_words();
+    let solver = WordleSolver::new(words.clone()).unwrap();
+    let exact = optimal_tree(&solver, OptimalConfig::default()).unwrap();
+    let narrow = optimal_tree(&solver, OptimalConfig { max_depth: Some(6), breadth: Some(1) }).unwrap();
+
+    assert!(narrow.total_guesses() >= exact.total_guesses());
+    for target in &words {
+        assert_eq!(play(&narrow, target).last().unwrap(), target);
+    }
+}
+
+#[test]
+fn test_optimal_tree_follows_hard_mode() {
+    let words = get_test_words();
+    let mut solver = WordleSolver::new(words.clone()).unwrap();
+    solver.set_hard_mode_rules(HardMode::Ultra);
+    let tree = optimal_tree(&solver, OptimalConfig::default()).unwrap();
+
+    for target in &words {
+        let mut constraints = HardModeConstraints::new();
+        for guess in play(&tree, target) {
+            assert!(constraints.is_valid(&guess), "Ultra mode violation: {}", guess);
+            constraints.update(&guess, FeedbackPattern::calculate(&guess, target));
+        }
+    }
+}
+
+#[test]
+fn test_optimal_tree_from_midgame() {
+    let mut solver = solver();
+    solver.apply_feedback("slate", FeedbackPattern::calculate("slate", "roast")).unwrap();
+    let tree = optimal_tree(&solver, OptimalConfig::default()).unwrap();
+    assert_eq!(tree.num_answers(), solver.remaining_count());
+
+    solver.apply_feedback("roast", FeedbackPattern::calculate("roast", "roast")).unwrap();
+    assert_eq!(optimal_tree(&solver, OptimalConfig::default()).unwrap().total_guesses(), 1);
+}
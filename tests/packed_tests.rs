@@ -0,0 +1,70 @@
+use proptest::prelude::*;
+use wordle_bot::{FeedbackPattern, PackedWord, WordleSolver, MAX_WORD_LENGTH};
+
+fn packed(guess: &str, target: &str) -> FeedbackPattern {
+    FeedbackPattern::calculate_packed(&PackedWord::new(guess).unwrap(), &PackedWord::new(target).unwrap())
+}
+
+#[test]
+fn test_known_patterns() {
+    for (guess, target, expected) in [
+        ("crane", "crane", "ggggg"),
+        ("crane", "toast", "bbgbb"),
+        ("speed", "abide", "bbyby"),
+        ("eerie", "there", "ybybg"),
+        ("lllll", "hello", "bbggb"),
+        ("fuzzy", "crane", "bbbbb"),
+    ] {
+        let pattern = packed(guess, target);
+        assert_eq!(pattern.to_letter_string(), expected, "{} against {}", guess, target);
+        assert_eq!(pattern, FeedbackPattern::calculate(guess, target));
+    }
+}
+
+#[test]
+fn test_packed_word() {
+    let word = PackedWord::new("abide").unwrap();
+    assert_eq!(word.len(), 5);
+    assert!(!word.is_empty());
+    assert_eq!(word, PackedWord::new("abide").unwrap());
+    assert_ne!(word, PackedWord::new("abode").unwrap());
+
+    assert!(PackedWord::new("Abide").is_err());
+    assert!(PackedWord::new("ab1de").is_err());
+    assert!(PackedWord::new("abcdefghijk").is_err());
+}
+
+#[test]
+fn test_solver_without_matrix_matches_calculate() {
+    let words: Vec<String> = ["crane", "slate", "eerie", "there", "speed", "abide"]
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+    let solver = WordleSolver::new(words.clone()).unwrap();
+    for (g, guess) in words.iter().enumerate() {
+        for (a, answer) in words.iter().enumerate() {
+            assert_eq!(solver.pattern_for(g, a), FeedbackPattern::calculate(guess, answer));
+        }
+    }
+}
+
+/// Two words of the same length, from a small alphabet so repeated letters are common
+fn word_pair() -> impl Strategy<Value = (String, String)> {
+    (1..=MAX_WORD_LENGTH).prop_flat_map(|len| {
+        let word = proptest::collection::vec(prop::sample::select(b"abcdez".to_vec()), len)
+            .prop_map(|bytes| String::from_utf8(bytes).unwrap());
+        (word.clone(), word)
+    })
+}
+
+proptest! {
+    #[test]
+    fn packed_matches_calculate((guess, target) in word_pair()) {
+        prop_assert_eq!(packed(&guess, &target), FeedbackPattern::calculate(&guess, &target));
+    }
+
+    #[test]
+    fn packed_matches_calculate_any_letters(guess in "[a-z]{5}", target in "[a-z]{5}") {
+        prop_assert_eq!(packed(&guess, &target), FeedbackPattern::calculate(&guess, &target));
+    }
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use common::get_test_words;
+use wordle_bot::{FeedbackPattern, WordPriors, WordleSolver};
+
+#[test]
+fn test_parse_frequency_list() {
+    let priors = WordPriors::parse("# word counts\ncrane\t100\nSLATE 50\n\nraise\t0\n").unwrap();
+    assert_eq!(priors.len(), 3);
+    assert!(priors.contains("slate"));
+    assert_eq!(priors.weight("crane"), 100.0);
+    assert_eq!(priors.weight("slate"), 50.0);
+    // Zero counts and missing words fall back to the rarest known word
+    assert_eq!(priors.weight("raise"), 50.0);
+    assert_eq!(priors.weight("beast"), 50.0);
+}
+
+#[test]
+fn test_parse_rejects_bad_lines() {
+    for text in ["crane", "crane 10 extra", "crane ten", "crane -1", "crane NaN"] {
+        let err = WordPriors::parse(&format!("slate\t5\n{}\n", text)).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 2:"), "{}", err);
+    }
+}
+
+#[test]
+fn test_uniform_priors_match_unweighted() {
+    let words = get_test_words();
+    let plain = WordleSolver::new(words.clone()).unwrap();
+    let mut weighted = WordleSolver::new(words.clone()).unwrap();
+    weighted.set_priors(&WordPriors::from_counts(words.iter().map(|w| (w, 7.0))));
+    assert!(weighted.has_priors());
+
+    for word in &words {
+        let a = plain.calculate_entropy_for_word(word).unwrap();
+        let b = weighted.calculate_entropy_for_word(word).unwrap();
+        assert!((a - b).abs() < 1e-9);
+    }
+    assert_eq!(plain.find_best_guess().unwrap().word, weighted.find_best_guess().unwrap().word);
+}
+
+#[test]
+fn test_weighted_entropy() {
+    let words: Vec<String> = ["crane", "crate", "slate"].iter().map(|s| s.to_string()).collect();
+    let mut solver = WordleSolver::new(words).unwrap();
+    solver.set_priors(&WordPriors::from_counts([("crane", 6.0), ("crate", 1.0), ("slate", 1.0)]));
+
+    // "crane" splits the three words apart: probabilities 6/8, 1/8, 1/8
+    let expected = -(0.75f64 * 0.75f64.log2() + 2.0 * 0.125 * 0.125f64.log2());
+    assert!((solver.calculate_entropy_for_word("crane").unwrap() - expected).abs() < 1e-9);
+    // So does "crank", which is scored without the guess index
+    assert!((solver.calculate_entropy_for_word("crank").unwrap() - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_posteriors() {
+    let words = get_test_words();
+    let mut solver = WordleSolver::new(words.clone()).unwrap();
+    let counts: Vec<(String, f64)> = words.iter().enumerate().map(|(i, w)| (w.clone(), (i + 1) as f64)).collect();
+    solver.set_priors(&WordPriors::from_counts(counts));
+
+    let posteriors = solver.posteriors();
+    assert_eq!(posteriors.len(), words.len());
+    assert_eq!(posteriors[0].0, "beast");
+    assert!((posteriors.iter().map(|(_, p)| p).sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!(posteriors.windows(2).all(|w| w[0].1 >= w[1].1));
+
+    solver.apply_feedback("slate", FeedbackPattern::calculate("slate", "toast")).unwrap();
+    let total: f64 = solver.posteriors().iter().map(|(_, p)| p).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+    assert_eq!(solver.answer_probability(1), 0.0, "slate was ruled out");
+}
+
+#[test]
+fn test_two_candidates_guess_the_likelier() {
+    let words: Vec<String> = ["crane", "crate"].iter().map(|s| s.to_string()).collect();
+    let mut solver = WordleSolver::new(words).unwrap();
+    assert_eq!(solver.find_best_guess().unwrap().expected_guesses, Some(1.5));
+
+    solver.set_priors(&WordPriors::from_counts([("crane", 1.0), ("crate", 3.0)]));
+    let best = solver.find_best_guess().unwrap();
+    assert_eq!(best.word, "crate");
+    assert!((best.answer_probability - 0.75).abs() < 1e-9);
+    assert!((best.expected_guesses.unwrap() - 1.25).abs() < 1e-9);
+
+    solver.clear_priors();
+    assert_eq!(solver.find_best_guess().unwrap().word, "crane");
+}
+
+#[test]
+fn test_two_candidates_with_overwhelming_prior() {
+    let words: Vec<String> = ["crane", "crate"].iter().map(|s| s.to_string()).collect();
+    let mut solver = WordleSolver::new(words).unwrap();
+
+    // The probability of CRATE rounds to exactly 1
+    solver.set_priors(&WordPriors::from_counts([("crane", 1.0), ("crate", 1e20)]));
+    let best = solver.find_best_guess().unwrap();
+    assert_eq!(best.word, "crate");
+    assert_eq!(best.answer_probability, 1.0);
+    assert_eq!(best.entropy, 0.0);
+    assert!(best.expected_remaining.is_finite());
+}
+
+#[test]
+fn test_analysis_answer_probability() {
+    let words = get_test_words();
+    let mut solver = WordleSolver::new(words.clone()).unwrap();
+    let counts = words.iter().map(|w| (w, if w == "crane" { 91.0 } else { 1.0 }));
+    solver.set_priors(&WordPriors::from_counts(counts));
+
+    for analysis in solver.find_best_guesses(words.len()) {
+        if !analysis.is_possible_answer {
+            assert_eq!(analysis.answer_probability, 0.0);
+        } else if analysis.word == "crane" {
+            assert!((analysis.answer_probability - 0.91).abs() < 1e-9);
+        } else {
+            assert!((analysis.answer_probability - 0.01).abs() < 1e-9);
+        }
+    }
+}
@@ -1,43 +1,58 @@
-use wordle_bot::{load_dictionary, FeedbackPattern, WordleSolver};
+mod common;
 
-fn get_test_words() -> Vec<String> {
-    vec![
-        "crane".to_string(),
-        "slate".to_string(),
-        "trace".to_string(),
-        "crate".to_string(),
-        "raise".to_string(),
-        "arise".to_string(),
-        "stare".to_string(),
-        "roast".to_string(),
-        "toast".to_string(),
-        "beast".to_string(),
-    ]
+use common::{get_test_words, solver};
+use wordle_bot::{
+    load_dictionary, FeedbackPattern, HardModeConstraints, SolveOutcome, WordleError, WordleSolver, MAX_GUESSES,
+};
+
+fn state(solver: &WordleSolver) -> (Vec<String>, HardModeConstraints) {
+    let answers = solver.possible_answers().iter().map(|w| w.to_string()).collect();
+    (answers, solver.constraints().clone())
 }
 
 #[test]
 fn test_solver_creation() {
     let words = get_test_words();
-    let solver = WordleSolver::new(words.clone());
+    let solver = WordleSolver::new(words.clone()).unwrap();
     assert_eq!(solver.remaining_count(), words.len());
 }
 
 #[test]
 fn test_apply_feedback() {
     let words = get_test_words();
-    let mut solver = WordleSolver::new(words);
+    let mut solver = WordleSolver::new(words).unwrap();
 
     let pattern = FeedbackPattern::calculate("crane", "crate");
-    solver.apply_feedback("crane", pattern);
+    solver.apply_feedback("crane", pattern).unwrap();
 
     assert!(solver.remaining_count() < 10);
-    assert!(solver.possible_answers().contains(&"crate".to_string()));
+    assert!(solver.possible_answers().contains(&"crate"));
+}
+
+#[test]
+fn test_candidate_set() {
+    let mut solver = solver();
+    assert_eq!(solver.candidates().len(), 10);
+    assert!(solver.is_possible_answer("crane"));
+
+    let pattern = FeedbackPattern::calculate("crane", "crate");
+    solver.apply_feedback("crane", pattern).unwrap();
+    assert!(solver.is_possible_answer("crate"));
+    assert!(!solver.is_possible_answer("crane"));
+    assert!(!solver.is_possible_answer("zzzzz"));
+
+    let words: Vec<&str> = solver.candidates().words(solver.answers()).collect();
+    assert_eq!(words, solver.possible_answers());
+    assert_eq!(solver.candidates().iter().collect::<Vec<_>>(), solver.candidate_indices());
+
+    solver.reset();
+    assert_eq!(solver.candidates().len(), 10);
 }
 
 #[test]
 fn test_find_best_guess() {
     let words = get_test_words();
-    let solver = WordleSolver::new(words);
+    let solver = WordleSolver::new(words).unwrap();
     let best = solver.find_best_guess();
 
     assert!(best.is_some());
@@ -48,7 +63,7 @@ fn test_find_best_guess() {
 
 #[test]
 fn test_find_best_guess_single_answer() {
-    let solver = WordleSolver::new(vec!["crane".to_string()]);
+    let solver = WordleSolver::new(vec!["crane".to_string()]).unwrap();
     let best = solver.find_best_guess();
 
     assert!(best.is_some());
@@ -60,9 +75,9 @@ fn test_find_best_guess_single_answer() {
 #[test]
 fn test_solve_for_target() {
     let words = get_test_words();
-    let mut solver = WordleSolver::new(words);
+    let mut solver = WordleSolver::new(words).unwrap();
 
-    let guesses = solver.solve_for_target("crate");
+    let guesses = solver.solve_for_target("crate").unwrap().guesses;
 
     assert!(!guesses.is_empty());
     assert!(guesses.len() <= 6);
@@ -77,8 +92,8 @@ fn test_solve_various_targets() {
     let words = get_test_words();
 
     for target in &words {
-        let mut solver = WordleSolver::new(words.clone());
-        let guesses = solver.solve_for_target(target);
+        let mut solver = WordleSolver::new(words.clone()).unwrap();
+        let guesses = solver.solve_for_target(target).unwrap().guesses;
 
         assert!(!guesses.is_empty(), "Failed to solve for target: {}", target);
         assert!(guesses.len() <= 6, "Too many guesses for target: {}", target);
@@ -97,9 +112,9 @@ fn test_entropy_calculation() {
         "crate".to_string(),
         "slate".to_string(),
     ];
-    let solver = WordleSolver::new(words);
+    let solver = WordleSolver::new(words).unwrap();
 
-    let entropy = solver.calculate_entropy_for_word("crane");
+    let entropy = solver.calculate_entropy_for_word("crane").unwrap();
     assert!(entropy > 0.0);
     assert!(entropy <= 2.0);
 }
@@ -107,10 +122,10 @@ fn test_entropy_calculation() {
 #[test]
 fn test_reset() {
     let words = get_test_words();
-    let mut solver = WordleSolver::new(words.clone());
+    let mut solver = WordleSolver::new(words.clone()).unwrap();
 
     let pattern = FeedbackPattern::calculate("crane", "toast");
-    solver.apply_feedback("crane", pattern);
+    solver.apply_feedback("crane", pattern).unwrap();
 
     assert!(solver.remaining_count() < words.len());
 
@@ -121,7 +136,7 @@ fn test_reset() {
 #[test]
 fn test_get_top_guesses() {
     let words = get_test_words();
-    let solver = WordleSolver::new(words);
+    let solver = WordleSolver::new(words).unwrap();
 
     let top_5 = solver.get_top_guesses(5);
     assert_eq!(top_5.len(), 5);
@@ -134,9 +149,9 @@ fn test_get_top_guesses() {
 #[test]
 fn test_with_full_dictionary() {
     let words = load_dictionary();
-    let mut solver = WordleSolver::new(words);
+    let mut solver = WordleSolver::new(words).unwrap();
 
-    let guesses = solver.solve_for_target("crane");
+    let guesses = solver.solve_for_target("crane").unwrap().guesses;
 
     assert!(!guesses.is_empty());
     assert!(guesses.len() <= 6);
@@ -149,11 +164,11 @@ fn test_with_full_dictionary() {
 #[test]
 fn test_hard_mode() {
     let words = get_test_words();
-    let mut solver = WordleSolver::new(words);
+    let mut solver = WordleSolver::new(words).unwrap();
     solver.set_hard_mode(true);
 
     let pattern = FeedbackPattern::calculate("crane", "crate");
-    solver.apply_feedback("crane", pattern);
+    solver.apply_feedback("crane", pattern).unwrap();
 
     let guesses = solver.find_best_guesses(10);
     for g in &guesses {
@@ -164,7 +179,7 @@ fn test_hard_mode() {
 #[test]
 fn test_find_best_guesses() {
     let words = get_test_words();
-    let solver = WordleSolver::new(words);
+    let solver = WordleSolver::new(words).unwrap();
 
     let guesses = solver.find_best_guesses(3);
     assert_eq!(guesses.len(), 3);
@@ -177,11 +192,14 @@ fn test_find_best_guesses() {
 #[test]
 fn test_empty_possible_answers() {
     let words = get_test_words();
-    let mut solver = WordleSolver::new(words);
+    let mut solver = WordleSolver::new(words).unwrap();
     
-    // Filter to empty by applying impossible constraints
-    solver.apply_feedback("zzzzz", FeedbackPattern::ALL_CORRECT);
+    // Impossible feedback is rejected instead of emptying the candidates
+    let err = solver.apply_feedback("zzzzz", FeedbackPattern::ALL_CORRECT).unwrap_err();
+    assert!(matches!(err, WordleError::InconsistentFeedback { .. }));
+    assert_eq!(solver.remaining_count(), 10);
 
+    let solver = WordleSolver::new(Vec::new()).unwrap();
     assert!(solver.find_best_guess().is_none());
     assert!(solver.get_top_guesses(5).is_empty());
 }
@@ -189,7 +207,7 @@ fn test_empty_possible_answers() {
 #[test]
 fn test_two_remaining_words() {
     let words = vec!["crane".to_string(), "trace".to_string()];
-    let solver = WordleSolver::new(words);
+    let solver = WordleSolver::new(words).unwrap();
 
     let best = solver.find_best_guess();
     assert!(best.is_some());
@@ -202,10 +220,10 @@ fn test_two_remaining_words() {
 #[test]
 fn test_solve_difficult_word() {
     let words = load_dictionary();
-    let mut solver = WordleSolver::new(words);
+    let mut solver = WordleSolver::new(words).unwrap();
 
     solver.reset();
-    let guesses = solver.solve_for_target("fuzzy");
+    let guesses = solver.solve_for_target("fuzzy").unwrap().guesses;
 
     if !guesses.is_empty() {
         assert!(guesses.len() <= 6);
@@ -215,3 +233,307 @@ fn test_solve_difficult_word() {
     }
 }
 
+
+#[test]
+fn test_separate_answers_and_guesses() {
+    let answers = vec!["crate".to_string(), "trace".to_string(), "slate".to_string()];
+    let guesses = get_test_words();
+    let solver = WordleSolver::with_answers(answers.clone(), guesses.clone()).unwrap();
+
+    assert_eq!(solver.remaining_count(), answers.len());
+    assert_eq!(solver.answers(), answers.as_slice());
+    assert_eq!(solver.all_words().len(), guesses.len());
+
+    let top = solver.find_best_guesses(guesses.len());
+    assert_eq!(top.len(), guesses.len());
+    for analysis in &top {
+        assert_eq!(analysis.is_possible_answer, answers.contains(&analysis.word));
+    }
+}
+
+#[test]
+fn test_answers_added_to_guesses() {
+    let answers = vec!["crate".to_string(), "zesty".to_string()];
+    let guesses = vec!["crane".to_string(), "crate".to_string()];
+    let solver = WordleSolver::with_answers(answers, guesses).unwrap();
+
+    assert_eq!(solver.all_words().len(), 3);
+    assert!(solver.all_words().contains(&"zesty".to_string()));
+}
+
+#[test]
+fn test_split_apply_feedback_and_reset() {
+    let answers = vec!["crate".to_string(), "trace".to_string(), "toast".to_string()];
+    let mut solver = WordleSolver::with_answers(answers, get_test_words()).unwrap();
+
+    let pattern = FeedbackPattern::calculate("slate", "toast");
+    solver.apply_feedback("slate", pattern).unwrap();
+    assert_eq!(solver.possible_answers(), vec!["toast"]);
+
+    solver.reset();
+    assert_eq!(solver.remaining_count(), 3);
+}
+
+#[test]
+fn test_benchmark_uses_answer_pool() {
+    let answers = vec!["crate".to_string(), "toast".to_string()];
+    let solver = WordleSolver::with_answers(answers, get_test_words()).unwrap();
+
+    let distribution = solver.benchmark_guess_distribution();
+    let total: usize = distribution.iter().map(|(_, c)| c).sum();
+    assert_eq!(total, 2);
+}
+
+#[test]
+fn test_six_letter_words() {
+    let words: Vec<String> = ["planet", "plants", "planes", "staple", "pastel", "petals"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+    for target in &words {
+        let mut solver = WordleSolver::new(words.clone()).unwrap();
+        assert_eq!(solver.word_length(), 6);
+
+        let guesses = solver.solve_for_target(target).unwrap().guesses;
+        let (final_guess, final_pattern) = guesses.last().unwrap();
+        assert!(final_pattern.is_win(), "Didn't win for target: {}", target);
+        assert_eq!(final_guess, target);
+    }
+}
+
+#[test]
+fn test_mismatched_lengths_rejected() {
+    let words = vec!["crane".to_string(), "planet".to_string(), "slate".to_string()];
+    assert_eq!(
+        WordleSolver::new(words).unwrap_err(),
+        WordleError::WrongLength {
+            word: "planet".to_string(),
+            expected: 5
+        }
+    );
+
+    let guesses = vec!["crane".to_string(), "slates".to_string()];
+    assert_eq!(
+        WordleSolver::with_answers(vec!["crane".to_string()], guesses).unwrap_err(),
+        WordleError::WrongLength {
+            word: "slates".to_string(),
+            expected: 5
+        }
+    );
+}
+
+#[test]
+fn test_hard_mode_seven_letters() {
+    let words: Vec<String> = ["example", "exclaim", "explain", "sampler"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let mut solver = WordleSolver::new(words).unwrap();
+    solver.set_hard_mode(true);
+
+    let pattern = FeedbackPattern::calculate("example", "explain");
+    solver.apply_feedback("example", pattern).unwrap();
+
+    for g in solver.find_best_guesses(10) {
+        assert!(g.word.starts_with("ex"), "Hard mode violation: {}", g.word);
+    }
+}
+
+#[test]
+fn test_apply_feedback_rejects_bad_input() {
+    let mut solver = solver();
+    let pattern = FeedbackPattern::parse("gybbb").unwrap();
+
+    let err = solver.apply_feedback("cr4ne", pattern).unwrap_err();
+    assert_eq!(err, WordleError::InvalidWord { word: "cr4ne".to_string(), position: 2 });
+    let err = solver.apply_feedback("CRANE", pattern).unwrap_err();
+    assert!(matches!(err, WordleError::InvalidWord { position: 0, .. }));
+    let err = solver.apply_feedback("cranes", pattern).unwrap_err();
+    assert_eq!(err, WordleError::WrongLength { word: "cranes".to_string(), expected: 5 });
+    let err = solver
+        .apply_feedback("crane", FeedbackPattern::parse_with_length("gybbbb", 6).unwrap())
+        .unwrap_err();
+    assert!(matches!(err, WordleError::PatternLength { expected: 5, .. }));
+
+    assert_eq!(solver.remaining_count(), 10);
+    assert!(solver.constraints().is_empty());
+}
+
+#[test]
+fn test_constructors_reject_invalid_words() {
+    let words = vec!["CRANE".to_string(), "SLATE".to_string()];
+    let err = WordleSolver::new(words).unwrap_err();
+    assert_eq!(err, WordleError::InvalidWord { word: "CRANE".to_string(), position: 0 });
+
+    let err = WordleSolver::with_answers(get_test_words(), vec!["cr4ne".to_string()]).unwrap_err();
+    assert_eq!(err, WordleError::InvalidWord { word: "cr4ne".to_string(), position: 2 });
+
+    let words = vec!["abcdefghijkl".to_string()];
+    assert!(matches!(WordleSolver::new(words).unwrap_err(), WordleError::WrongLength { .. }));
+}
+
+#[test]
+fn test_inconsistent_feedback_leaves_state_unchanged() {
+    let mut solver = solver();
+    solver.apply_feedback("crane", FeedbackPattern::calculate("crane", "crate")).unwrap();
+    let before = solver.possible_answers().len();
+    let constraints = solver.constraints().clone();
+
+    let pattern = FeedbackPattern::parse("bbbbb").unwrap();
+    let err = solver.apply_feedback("trace", pattern).unwrap_err();
+    assert_eq!(err, WordleError::InconsistentFeedback { guess: "trace".to_string(), pattern });
+    assert_eq!(err.to_string(), "no remaining word gives bbbbb for 'trace'");
+
+    assert_eq!(solver.possible_answers().len(), before);
+    assert_eq!(solver.constraints(), &constraints);
+}
+
+#[test]
+fn test_solve_for_target_errors() {
+    let mut solver = solver();
+    assert_eq!(solver.solve_for_target("zebra").unwrap_err(), WordleError::NotInDictionary("zebra".to_string()));
+    assert!(matches!(solver.solve_for_target("crate!").unwrap_err(), WordleError::InvalidWord { position: 5, .. }));
+    assert!(matches!(solver.solve_for_target("cat").unwrap_err(), WordleError::WrongLength { expected: 5, .. }));
+    assert!(solver.calculate_entropy_for_word("Crane").is_err());
+}
+
+#[test]
+fn test_max_guesses() {
+    let words: Vec<String> = ["bills", "fills", "gills", "hills", "kills", "mills", "pills", "sills", "tills", "wills"]
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+    let mut solver = WordleSolver::new(words.clone()).unwrap();
+    assert_eq!(solver.max_guesses(), Some(MAX_GUESSES));
+
+    // Each guess rules out only itself, so some words need ten guesses
+    let (slowest, result) = words
+        .iter()
+        .map(|target| (target, solver.clone().solve_for_target(target).unwrap()))
+        .max_by_key(|(_, result)| result.num_guesses())
+        .unwrap();
+    assert_eq!(result.num_guesses(), MAX_GUESSES);
+    assert_eq!(result.outcome, SolveOutcome::Unsolved);
+    assert!(!result.is_solved());
+
+    // Benchmarks ignore the limit and report the true counts
+    assert_eq!(solver.benchmark_guess_distribution().last().unwrap().0, 10);
+
+    solver.set_max_guesses(Some(2));
+    assert_eq!(solver.clone().solve_for_target(slowest).unwrap().num_guesses(), 2);
+
+    solver.set_max_guesses(None);
+    for target in &words {
+        let result = solver.clone().solve_for_target(target).unwrap();
+        assert!(result.is_solved());
+        assert_eq!(&result.guesses.last().unwrap().0, target);
+    }
+}
+
+#[test]
+fn test_solve_with_inconsistent_feedback() {
+    let mut solver = solver();
+    // Every test word has an 'a', so no guess can come back all gray
+    let err = solver
+        .solve_with_feedback(|_| FeedbackPattern::parse("bbbbb").unwrap())
+        .unwrap_err();
+    assert!(matches!(err, WordleError::InconsistentFeedback { .. }));
+}
+
+#[test]
+fn test_undo_and_redo() {
+    let mut solver = solver();
+    solver.set_hard_mode(true);
+    let first = FeedbackPattern::calculate("slate", "toast");
+    let second = FeedbackPattern::calculate("roast", "toast");
+
+    solver.apply_feedback("slate", first).unwrap();
+    let after_first = state(&solver);
+    solver.apply_feedback("roast", second).unwrap();
+    let after_second = state(&solver);
+    assert_eq!(solver.history(), &[("slate".to_string(), first), ("roast".to_string(), second)]);
+
+    assert_eq!(solver.undo(), Some(("roast".to_string(), second)));
+    assert_eq!(state(&solver), after_first);
+    assert_eq!(solver.history().len(), 1);
+
+    assert_eq!(solver.redo(), Some(("roast".to_string(), second)));
+    assert_eq!(state(&solver), after_second);
+    assert_eq!(solver.redo(), None);
+
+    solver.undo();
+    solver.undo();
+    assert_eq!(solver.undo(), None);
+    assert_eq!(solver.remaining_count(), 10);
+    assert!(solver.constraints().is_empty());
+}
+
+#[test]
+fn test_new_feedback_clears_redo() {
+    let mut solver = solver();
+    solver.apply_feedback("crane", FeedbackPattern::calculate("crane", "crate")).unwrap();
+    solver.undo();
+
+    solver.apply_feedback("slate", FeedbackPattern::calculate("slate", "crate")).unwrap();
+    assert_eq!(solver.redo(), None);
+    assert_eq!(solver.history().len(), 1);
+
+    solver.reset();
+    assert!(solver.history().is_empty());
+    assert_eq!(solver.undo(), None);
+}
+
+#[test]
+fn test_rewind_to() {
+    let mut solver = solver();
+    let steps = [("raise", "beast"), ("slate", "beast"), ("toast", "beast")];
+    let mut states = vec![state(&solver).0];
+    for (guess, target) in steps {
+        solver.apply_feedback(guess, FeedbackPattern::calculate(guess, target)).unwrap();
+        states.push(state(&solver).0);
+    }
+
+    solver.rewind_to(5);
+    assert_eq!(solver.history().len(), 3);
+
+    solver.rewind_to(1);
+    assert_eq!(state(&solver).0, states[1]);
+    assert_eq!(solver.history().len(), 1);
+
+    // Redo brings the steps back in their original order
+    assert_eq!(solver.redo().unwrap().0, "slate");
+    assert_eq!(state(&solver).0, states[2]);
+    assert_eq!(solver.redo().unwrap().0, "toast");
+    assert_eq!(state(&solver).0, states[3]);
+}
+
+#[test]
+fn test_opening() {
+    let mut solver = solver();
+    solver.set_opening(vec!["beast".to_string(), "toast".to_string()]).unwrap();
+    assert_eq!(solver.next_guess().unwrap().word, "beast");
+
+    // BEAST leaves only CRANE, so TOAST is skipped
+    let result = solver.clone().solve_for_target("crane").unwrap();
+    let path: Vec<&str> = result.guesses.iter().map(|(guess, _)| guess.as_str()).collect();
+    assert_eq!(path, ["beast", "crane"]);
+
+    // Without a cutoff the whole opening is played
+    solver.set_opening_cutoff(0);
+    let result = solver.clone().solve_for_target("crane").unwrap();
+    let path: Vec<&str> = result.guesses.iter().map(|(guess, _)| guess.as_str()).collect();
+    assert_eq!(path, ["beast", "toast", "crane"]);
+
+    // Straying from the opening hands over to the strategy
+    solver.apply_feedback("crane", FeedbackPattern::calculate("crane", "slate")).unwrap();
+    assert_eq!(solver.next_guess().unwrap().word, solver.find_best_guess().unwrap().word);
+
+    assert_eq!(
+        solver.set_opening(vec!["zzzzz".to_string()]),
+        Err(WordleError::NotInDictionary("zzzzz".to_string()))
+    );
+    assert_eq!(solver.opening(), ["beast", "toast"]);
+    solver.set_opening(Vec::new()).unwrap();
+    assert!(solver.opening().is_empty());
+}
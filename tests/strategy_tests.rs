@@ -0,0 +1,217 @@
+mod common;
+
+use common::{get_test_words, solver};
+use std::sync::Arc;
+use wordle_bot::{
+    strategy_from_name, ExpectedRemainingStrategy, FeedbackPattern, GuessAnalysis, GuessStrategy,
+    LetterFrequencyStrategy, MinimaxStrategy, RandomCandidateStrategy, SolveOutcome, WordleSolver,
+    STRATEGY_NAMES,
+};
+
+fn solver_with(strategy: Arc<dyn GuessStrategy>) -> WordleSolver {
+    let mut solver = solver();
+    solver.set_strategy(strategy);
+    solver
+}
+
+fn largest_bucket(solver: &WordleSolver, word: &str) -> usize {
+    let g = solver.all_words().iter().position(|w| w == word).unwrap();
+    solver
+        .partition(g, &solver.candidate_indices())
+        .iter()
+        .map(|(_, bucket)| bucket.len())
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn test_default_strategy_is_entropy() {
+    let solver = solver();
+    assert_eq!(solver.strategy().name(), "entropy");
+}
+
+#[test]
+fn test_every_named_strategy_resolves() {
+    for name in STRATEGY_NAMES {
+        let strategy = strategy_from_name(name).unwrap();
+        assert_eq!(strategy.name(), *name);
+    }
+    assert!(strategy_from_name("bogus").is_none());
+}
+
+#[test]
+fn test_every_strategy_solves_all_targets() {
+    let words = get_test_words();
+    for name in STRATEGY_NAMES {
+        for target in &words {
+            let mut solver = solver_with(strategy_from_name(name).unwrap());
+            let guesses = solver.solve_for_target(target).unwrap().guesses;
+
+            let (final_guess, final_pattern) = guesses.last().unwrap();
+            assert!(final_pattern.is_win(), "{} didn't win for target: {}", name, target);
+            assert_eq!(final_guess, target);
+        }
+    }
+}
+
+#[test]
+fn test_minimax_minimizes_largest_bucket() {
+    let solver = solver_with(Arc::new(MinimaxStrategy));
+    let best = solver.find_best_guess().unwrap();
+    let best_worst_case = largest_bucket(&solver, &best.word);
+
+    for word in solver.all_words() {
+        assert!(largest_bucket(&solver, word) >= best_worst_case);
+    }
+}
+
+#[test]
+fn test_expected_remaining_ranking() {
+    let solver = solver_with(Arc::new(ExpectedRemainingStrategy));
+    let top = solver.find_best_guesses(3);
+    assert_eq!(top.len(), 3);
+}
+
+#[test]
+fn test_letter_frequency_prefers_common_letters() {
+    let words: Vec<String> = ["crane", "crate", "trace", "fuzzy"].iter().map(|s| s.to_string()).collect();
+    let mut solver = WordleSolver::new(words).unwrap();
+    solver.set_strategy(Arc::new(LetterFrequencyStrategy));
+
+    let top = solver.find_best_guesses(4);
+    assert_eq!(top.last().unwrap().word, "fuzzy");
+}
+
+#[test]
+fn test_random_candidate_is_deterministic_candidate() {
+    let a = solver_with(Arc::new(RandomCandidateStrategy::new(7)));
+    let b = solver_with(Arc::new(RandomCandidateStrategy::new(7)));
+
+    let picks_a: Vec<String> = a.find_best_guesses(5).into_iter().map(|g| g.word).collect();
+    let picks_b: Vec<String> = b.find_best_guesses(5).into_iter().map(|g| g.word).collect();
+    assert_eq!(picks_a, picks_b);
+    assert_eq!(picks_a.len(), 5);
+    for word in &picks_a {
+        assert!(a.possible_answers().contains(&word.as_str()));
+    }
+}
+
+/// A strategy defined outside the crate: always guess the alphabetically
+/// first remaining candidate
+#[derive(Debug)]
+struct Alphabetical;
+
+impl GuessStrategy for Alphabetical {
+    fn name(&self) -> &'static str {
+        "alphabetical"
+    }
+
+    fn rank(&self, solver: &WordleSolver, n: usize) -> Vec<GuessAnalysis> {
+        let mut candidates = solver.candidate_indices();
+        candidates.sort_by_key(|&a| solver.answers()[a].clone());
+        candidates
+            .into_iter()
+            .take(n)
+            .map(|a| {
+                let g = solver.guess_index_of_answer(a);
+                solver.analysis(g, solver.entropy_among(g, &solver.candidate_indices()), true)
+            })
+            .collect()
+    }
+}
+
+/// Always guesses a fixed word, whether or not it helps
+#[derive(Debug)]
+struct Stubborn(&'static str);
+
+impl GuessStrategy for Stubborn {
+    fn name(&self) -> &'static str {
+        "stubborn"
+    }
+
+    fn rank(&self, solver: &WordleSolver, _n: usize) -> Vec<GuessAnalysis> {
+        let g = solver.guess_index_of(self.0).unwrap();
+        vec![solver.analysis(g, 0.0, false)]
+    }
+}
+
+#[test]
+fn test_strategy_making_no_progress_is_stuck() {
+    let mut guesses = get_test_words();
+    guesses.push("fuzzy".to_string());
+    let mut solver = WordleSolver::with_answers(get_test_words(), guesses).unwrap();
+    solver.set_strategy(Arc::new(Stubborn("fuzzy")));
+    solver.set_max_guesses(None);
+
+    // FUZZY shares no letters with any answer, so it never rules one out
+    let result = solver.solve_for_target("toast").unwrap();
+    assert_eq!(result.outcome, SolveOutcome::Stuck);
+    assert_eq!(result.num_guesses(), 1);
+    assert_eq!(solver.remaining_count(), 10);
+}
+
+#[test]
+fn test_custom_strategy() {
+    let mut solver = solver_with(Arc::new(Alphabetical));
+    assert_eq!(solver.find_best_guess().unwrap().word, "arise");
+
+    let guesses = solver.solve_for_target("toast").unwrap().guesses;
+    assert_eq!(guesses.last().unwrap().0, "toast");
+}
+
+#[test]
+fn test_compare_strategies_in_benchmark() {
+    let mut averages = Vec::new();
+    for name in ["entropy", "random"] {
+        let solver = solver_with(strategy_from_name(name).unwrap());
+        averages.push(solver.benchmark_average_guesses());
+    }
+    assert!(averages.iter().all(|&avg| avg >= 1.0));
+}
+
+#[test]
+fn test_analysis_bucket_fields() {
+    let solver = solver();
+    for analysis in solver.find_best_guesses(10) {
+        assert_eq!(analysis.worst_case_remaining, largest_bucket(&solver, &analysis.word));
+        assert!(analysis.bucket_count >= 1);
+        assert!(analysis.bucket_count <= solver.remaining_count());
+        assert!(analysis.worst_case_remaining <= solver.remaining_count());
+    }
+}
+
+#[test]
+fn test_minimax_orders_by_worst_case_then_entropy() {
+    let solver = solver_with(Arc::new(MinimaxStrategy));
+    let top = solver.find_best_guesses(10);
+
+    for pair in top.windows(2) {
+        let (a, b) = (&pair[0], &pair[1]);
+        assert!(a.worst_case_remaining <= b.worst_case_remaining);
+        if a.worst_case_remaining == b.worst_case_remaining {
+            assert!(a.entropy >= b.entropy);
+        }
+    }
+}
+
+#[test]
+fn test_minimax_in_solve_with_feedback() {
+    let words = get_test_words();
+    for target in &words {
+        let mut solver = solver_with(Arc::new(MinimaxStrategy));
+        let guesses = solver
+            .solve_with_feedback(|guess| FeedbackPattern::calculate(guess, target))
+            .unwrap()
+            .guesses;
+        assert_eq!(&guesses.last().unwrap().0, target);
+        assert!(guesses.len() <= 6);
+    }
+}
+
+#[test]
+fn test_trivial_cases_report_buckets() {
+    let solver = WordleSolver::new(vec!["crane".to_string(), "trace".to_string()]).unwrap();
+    let best = solver.find_best_guess().unwrap();
+    assert_eq!(best.worst_case_remaining, 1);
+    assert_eq!(best.bucket_count, 2);
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use common::{get_test_words, solver};
+use wordle_bot::{
+    optimal_tree, DecisionTree, FeedbackPattern, OptimalConfig, TreeSolver, WordleError, WordleSolver,
+};
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("wordle-bot-test-{}-{}", std::process::id(), name))
+}
+
+#[test]
+fn test_tree_replays_solver() {
+    let words = get_test_words();
+    let solver = WordleSolver::new(words.clone()).unwrap();
+    let tree = DecisionTree::from_strategy(&solver).unwrap();
+    assert_eq!(tree.num_answers(), words.len());
+
+    let mut tree_solver = TreeSolver::new(tree);
+    for target in &words {
+        let mut fresh = solver.clone();
+        tree_solver.reset();
+        assert_eq!(tree_solver.solve_for_target(target).unwrap(), fresh.solve_for_target(target).unwrap());
+    }
+}
+
+#[test]
+fn test_text_round_trip() {
+    let tree = DecisionTree::from_strategy(&solver()).unwrap();
+    let text = tree.to_text();
+
+    assert_eq!(text.lines().count(), tree.num_answers());
+    for line in text.lines() {
+        // Every line ends with the answer, all green, and its guess count
+        let last = line.split_whitespace().last().unwrap();
+        let count = line.split_whitespace().count() / 2;
+        assert_eq!(last, format!("ggggg{}", count));
+    }
+    assert_eq!(DecisionTree::from_text(&text).unwrap(), tree);
+}
+
+#[test]
+fn test_json_round_trip() {
+    let tree = optimal_tree(&solver(), OptimalConfig::default()).unwrap();
+    let json = tree.to_json();
+    assert!(json.trim_start().starts_with('{'));
+    assert_eq!(DecisionTree::from_json(&json).unwrap(), tree);
+    assert_eq!(DecisionTree::parse(&json).unwrap(), tree);
+    assert_eq!(DecisionTree::parse(&tree.to_text()).unwrap(), tree);
+}
+
+#[test]
+fn test_save_and_open() {
+    let tree = DecisionTree::from_strategy(&solver()).unwrap();
+    for name in ["tree.txt", "tree.json"] {
+        let path = temp_path(name);
+        tree.save(&path).unwrap();
+        let solver = TreeSolver::open(&path).unwrap();
+        assert_eq!(solver.tree(), &tree);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
+
+#[test]
+fn test_text_without_counts() {
+    let text = "# hand-written\nslate ggggg\nslate bbgbb crane ggggg\n\nslate bbgbb crane bbggg roast ggggg\n";
+    let tree = DecisionTree::from_text(text).unwrap();
+    assert_eq!(tree.num_answers(), 3);
+    assert_eq!(tree.total_guesses(), 6);
+    assert!(tree.is_answer);
+    assert_eq!(tree.next(FeedbackPattern::parse("bbgbb").unwrap()).unwrap().guess, "crane");
+}
+
+#[test]
+fn test_invalid_text_rejected() {
+    let cases = [
+        "slate",
+        "slate bbbbb",
+        "slate ggggg crane ggggg",
+        "slate ggggg2",
+        "slate bbqbb crane ggggg",
+        "slate bbbbb crate ggggg\nslate bbbbb crane ggggg",
+        "slate ggggg\nslate ggggg",
+        "slate ggggg\ncrane ggggg",
+        "slate bbbbb cranes gggggg",
+        "",
+    ];
+    for text in cases {
+        let err = DecisionTree::from_text(text).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData, "accepted: {:?}", text);
+    }
+    let err = DecisionTree::from_text("slate ggggg\nslate bbbbb").unwrap_err();
+    assert!(err.to_string().starts_with("line 2:"));
+}
+
+#[test]
+fn test_invalid_json_rejected() {
+    let cases = [
+        "{",
+        r#"{"guess": "slate"}"#,
+        r#"{"guess": "slate", "next": {"ggggg": {"guess": "crane", "answer": true}}}"#,
+        r#"{"guess": "slate", "next": {"bbb": {"guess": "crane", "answer": true}}}"#,
+        r#"{"guess": "slate", "next": {"bbbbb": {"guess": "cranes", "answer": true}}}"#,
+    ];
+    for json in cases {
+        assert!(DecisionTree::from_json(json).is_err(), "accepted: {}", json);
+    }
+}
+
+#[test]
+fn test_tree_solver_follows_feedback() {
+    let tree = DecisionTree::from_strategy(&solver()).unwrap();
+    let mut solver = TreeSolver::new(tree.clone());
+    assert_eq!(solver.next_guess(), tree.guess);
+
+    let pattern = FeedbackPattern::calculate(&tree.guess, "toast");
+    solver.apply_feedback(pattern).unwrap();
+    assert_eq!(solver.current(), tree.next(pattern).unwrap());
+
+    // Feedback no answer in the tree can produce leaves the game unchanged
+    let before = solver.next_guess().to_string();
+    let err = solver.apply_feedback(FeedbackPattern::parse("yyyyy").unwrap()).unwrap_err();
+    assert!(matches!(err, WordleError::InconsistentFeedback { .. }));
+    assert_eq!(solver.next_guess(), before);
+
+    let err = solver.apply_feedback(FeedbackPattern::parse_with_length("bbbb", 4).unwrap()).unwrap_err();
+    assert!(matches!(err, WordleError::PatternLength { expected: 5, .. }));
+
+    solver.reset();
+    assert_eq!(solver.next_guess(), tree.guess);
+    assert_eq!(solver.solve_for_target("zebra").unwrap_err(), WordleError::NotInTree("zebra".to_string()));
+    assert!(matches!(solver.solve_for_target("Toast"), Err(WordleError::InvalidWord { position: 0, .. })));
+    assert!(matches!(solver.solve_for_target("toasts"), Err(WordleError::WrongLength { expected: 5, .. })));
+}
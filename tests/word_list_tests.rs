@@ -0,0 +1,57 @@
+use std::io::ErrorKind;
+use wordle_bot::{load_word_list, parse_word_list, WordleSolver};
+
+#[test]
+fn test_parse_valid_list() {
+    let words = parse_word_list("# fruit\ncrane\n\n  SLATE  \ntrace\n", None).unwrap();
+    assert_eq!(words, vec!["crane", "slate", "trace"]);
+}
+
+#[test]
+fn test_length_taken_from_first_word() {
+    let words = parse_word_list("banana\norange\n", None).unwrap();
+    assert_eq!(words.len(), 2);
+
+    let err = parse_word_list("banana\norange\n", Some(5)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err.to_string().contains("line 1: 'banana' has 6 letters, expected 5"));
+    assert!(err.to_string().contains("line 2:"));
+}
+
+#[test]
+fn test_every_problem_reported_with_line_number() {
+    let text = "apple\nMango\nkiwi\npe-ar\nappLE\nAPPLE\ncafé\nabcdefghijkl\n";
+    let message = parse_word_list(text, None).unwrap_err().to_string();
+
+    assert!(message.starts_with("7 invalid entries:"), "{}", message);
+    assert!(message.contains("line 2: 'Mango' mixes upper and lower case"));
+    assert!(message.contains("line 3: 'kiwi' has 4 letters, expected 5"));
+    assert!(message.contains("line 4: 'pe-ar' contains characters other than ASCII letters"));
+    assert!(message.contains("line 5: 'appLE' mixes upper and lower case"));
+    assert!(message.contains("line 6: 'APPLE' duplicates line 1"));
+    assert!(message.contains("line 7: 'café' contains characters other than ASCII letters"));
+    assert!(message.contains("line 8: 'abcdefghijkl' is longer than 10 letters"));
+}
+
+#[test]
+fn test_long_reports_are_truncated() {
+    let text = "crane\n".repeat(30);
+    let message = parse_word_list(&text, None).unwrap_err().to_string();
+    assert!(message.starts_with("29 invalid entries:"));
+    assert_eq!(message.lines().count(), 1 + 20 + 1);
+    assert!(message.ends_with("... and 9 more"));
+}
+
+#[test]
+fn test_load_word_list_from_file() {
+    let path = std::env::temp_dir().join(format!("wordle-bot-test-{}-words.txt", std::process::id()));
+    std::fs::write(&path, "crane\ncrate\ntrace\n").unwrap();
+    let answers = load_word_list(&path, Some(5)).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let solver = WordleSolver::with_answers(answers, wordle_bot::load_dictionary()).unwrap();
+    assert_eq!(solver.remaining_count(), 3);
+
+    let missing = load_word_list(&path, None).unwrap_err();
+    assert_eq!(missing.kind(), ErrorKind::NotFound);
+}